@@ -0,0 +1,100 @@
+//! ASCII-armored text envelope for parameters and outputs, so a payload can
+//! survive copy-paste, a JSON string field, or an email body where raw binary
+//! can't.
+//!
+//! # Format
+//!
+//! ```text
+//! SPFP v1
+//! <base64-encoded msgpack payload, wrapped at 64 columns>
+//! ---
+//! ```
+//!
+//! The header line carries the 4-char ASCII magic and the decimal version, so
+//! [`deserialize_parameters`](crate::deserialize_parameters)/[`deserialize_outputs`](crate::deserialize_outputs)
+//! can auto-detect armor: the byte right after the magic is an ASCII space,
+//! which a raw binary header's big-endian version field could only produce
+//! for a version at or above `0x20000000`.
+
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD as BASE64;
+
+use crate::error::{ArmorError, PeekError};
+
+const WRAP_COLUMNS: usize = 64;
+const SENTINEL: &str = "---";
+
+/// True if `bytes` looks like an armored envelope (of any magic) rather than
+/// a raw binary header.
+pub fn is_armored(bytes: &[u8]) -> bool {
+    bytes.len() > 4 && bytes[0..4].is_ascii() && bytes[4] == b' '
+}
+
+/// Render `payload` as an armored envelope under `magic`/`version`.
+pub(crate) fn armor(magic: &[u8; 4], version: u32, payload: &[u8]) -> String {
+    let magic_str = std::str::from_utf8(magic).expect("magic bytes are ASCII");
+    let mut out = format!("{magic_str} v{version}\n");
+    let body = BASE64.encode(payload);
+    for chunk in body.as_bytes().chunks(WRAP_COLUMNS) {
+        out.push_str(std::str::from_utf8(chunk).expect("base64 output is ASCII"));
+        out.push('\n');
+    }
+    out.push_str(SENTINEL);
+    out.push('\n');
+    out
+}
+
+/// Peek an armored envelope's declared version from its header line alone,
+/// without decoding the base64 body.
+pub(crate) fn peek_armored_version(bytes: &[u8], expected_magic: &[u8; 4]) -> Result<u32, PeekError> {
+    let text = std::str::from_utf8(bytes).map_err(|_| PeekError::InvalidMagic)?;
+    let header = text.lines().next().ok_or(PeekError::TooShort)?;
+    let (magic_str, version_str) = header.split_once(" v").ok_or(PeekError::InvalidVersion)?;
+    if magic_str.as_bytes() != expected_magic {
+        return Err(PeekError::InvalidMagic);
+    }
+    version_str.parse().map_err(|_| PeekError::InvalidVersion)
+}
+
+/// Parse an armored envelope, returning its declared version and decoded
+/// msgpack payload. The magic is checked against `expected_magic` here; the
+/// caller checks the version against what it expects, the same way
+/// [`crate::peek_parameters_version`] leaves version matching to the caller.
+pub(crate) fn dearmor(text: &str, expected_magic: &[u8; 4]) -> Result<(u32, Vec<u8>), ArmorError> {
+    let mut lines = text.lines();
+    let header = lines.next().ok_or(ArmorError::Empty)?;
+
+    let (magic_str, version_str) = header
+        .split_once(" v")
+        .ok_or_else(|| ArmorError::MalformedHeader(header.to_string()))?;
+    if magic_str.as_bytes() != expected_magic {
+        let expected = std::str::from_utf8(expected_magic)
+            .expect("magic bytes are ASCII")
+            .to_string();
+        return Err(ArmorError::InvalidMagic {
+            got: magic_str.to_string(),
+            expected,
+        });
+    }
+    let version: u32 = version_str
+        .parse()
+        .map_err(|_| ArmorError::MalformedHeader(header.to_string()))?;
+
+    let mut body = String::new();
+    let mut found_sentinel = false;
+    for line in lines {
+        if line == SENTINEL {
+            found_sentinel = true;
+            break;
+        }
+        body.push_str(line);
+    }
+    if !found_sentinel {
+        return Err(ArmorError::MissingSentinel);
+    }
+
+    let payload = BASE64
+        .decode(body)
+        .map_err(|e| ArmorError::InvalidBase64(e.to_string()))?;
+    Ok((version, payload))
+}
@@ -0,0 +1,138 @@
+//! Authenticated wire format for parameters: an HMAC-SHA256 tag over the
+//! header and payload, so a blob shipped across an untrusted channel can be
+//! rejected if it was tampered with or truncated in transit.
+//!
+//! # Format
+//!
+//! ```text
+//! [MAGIC: 4 bytes]["SPFA"][VERSION: 4 bytes big-endian u32][TAG: 32 bytes HMAC-SHA256][PAYLOAD: msgpack bytes]
+//! ```
+//!
+//! The tag is computed over `MAGIC || VERSION || PAYLOAD` under a
+//! caller-supplied key and verified in constant time before the payload is
+//! ever handed to `rmp_serde`. This is a distinct magic from [`PARAMETERS_MAGIC`](crate::PARAMETERS_MAGIC)
+//! so plain and authenticated blobs can never be confused with one another.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::error::{DeserializeError, PeekError, SerializeError};
+use crate::types::ParameterType;
+use crate::wire::{check_limits, decode_msgpack, DeserializeLimits};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Magic bytes identifying an authenticated parameters blob: "SPFA" in ASCII.
+pub const AUTHENTICATED_PARAMETERS_MAGIC: [u8; 4] = *b"SPFA";
+
+/// Current authenticated-parameters format version.
+pub const AUTHENTICATED_PARAMETERS_VERSION: u32 = 1;
+
+const TAG_SIZE: usize = 32;
+const HEADER_SIZE: usize = 8;
+const TAGGED_HEADER_SIZE: usize = HEADER_SIZE + TAG_SIZE;
+
+fn mac_with_key(key: &[u8]) -> HmacSha256 {
+    HmacSha256::new_from_slice(key).expect("HMAC accepts a key of any size")
+}
+
+/// Serialize `params` with an HMAC-SHA256 authentication tag computed under `key`.
+pub fn serialize_parameters_authenticated(
+    params: &[ParameterType],
+    key: &[u8],
+) -> Result<Vec<u8>, SerializeError> {
+    let payload = rmp_serde::to_vec(params).map_err(SerializeError::Payload)?;
+
+    let mut mac = mac_with_key(key);
+    mac.update(&AUTHENTICATED_PARAMETERS_MAGIC);
+    mac.update(&AUTHENTICATED_PARAMETERS_VERSION.to_be_bytes());
+    mac.update(&payload);
+    let tag = mac.finalize().into_bytes();
+
+    let mut buf = Vec::with_capacity(TAGGED_HEADER_SIZE + payload.len());
+    buf.extend_from_slice(&AUTHENTICATED_PARAMETERS_MAGIC);
+    buf.extend_from_slice(&AUTHENTICATED_PARAMETERS_VERSION.to_be_bytes());
+    buf.extend_from_slice(&tag);
+    buf.extend_from_slice(&payload);
+    Ok(buf)
+}
+
+/// Verify and deserialize a blob produced by [`serialize_parameters_authenticated`]
+/// under the same `key`, capped at [`DeserializeLimits::DEFAULT`].
+///
+/// Returns `DeserializeError::AuthenticationFailed` if the tag doesn't match
+/// (wrong key, or the data was tampered with or truncated) before the
+/// payload is deserialized.
+pub fn deserialize_parameters_authenticated(
+    bytes: &[u8],
+    key: &[u8],
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    if bytes.len() < TAGGED_HEADER_SIZE {
+        return Err(DeserializeError::Peek(PeekError::TooShort));
+    }
+    if bytes[0..4] != AUTHENTICATED_PARAMETERS_MAGIC {
+        return Err(DeserializeError::Peek(PeekError::InvalidMagic));
+    }
+    let version = u32::from_be_bytes(
+        bytes[4..HEADER_SIZE]
+            .try_into()
+            .expect("slice is exactly 4 bytes"),
+    );
+    if version != AUTHENTICATED_PARAMETERS_VERSION {
+        return Err(DeserializeError::UnsupportedVersion {
+            got: version,
+            expected: AUTHENTICATED_PARAMETERS_VERSION,
+        });
+    }
+
+    let tag = &bytes[HEADER_SIZE..TAGGED_HEADER_SIZE];
+    let payload = &bytes[TAGGED_HEADER_SIZE..];
+
+    let mut mac = mac_with_key(key);
+    mac.update(&bytes[0..HEADER_SIZE]);
+    mac.update(payload);
+    mac.verify_slice(tag)
+        .map_err(|_| DeserializeError::AuthenticationFailed)?;
+
+    check_limits(payload, DeserializeLimits::DEFAULT)?;
+    decode_msgpack(payload, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BitWidth, Signedness};
+
+    fn sample_params() -> Vec<ParameterType> {
+        vec![ParameterType::Plaintext {
+            bit_width: BitWidth::U32,
+            value: 7,
+            signedness: Signedness::Unsigned,
+        }]
+    }
+
+    #[test]
+    fn round_trips_under_the_same_key() {
+        let key = b"correct horse battery staple";
+        let bytes = serialize_parameters_authenticated(&sample_params(), key).unwrap();
+        let params = deserialize_parameters_authenticated(&bytes, key).unwrap();
+        assert_eq!(params.len(), 1);
+    }
+
+    #[test]
+    fn rejects_payload_verified_under_a_different_key() {
+        let bytes = serialize_parameters_authenticated(&sample_params(), b"key-a").unwrap();
+        let err = deserialize_parameters_authenticated(&bytes, b"key-b").unwrap_err();
+        assert!(matches!(err, DeserializeError::AuthenticationFailed));
+    }
+
+    #[test]
+    fn rejects_tampered_payload() {
+        let key = b"correct horse battery staple";
+        let mut bytes = serialize_parameters_authenticated(&sample_params(), key).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0x01;
+        let err = deserialize_parameters_authenticated(&bytes, key).unwrap_err();
+        assert!(matches!(err, DeserializeError::AuthenticationFailed));
+    }
+}
@@ -0,0 +1,120 @@
+//! `batch` subcommand: run one loaded program against many parameter payloads.
+
+use std::fs;
+use std::sync::Arc;
+
+use anyhow::{Context, Result, anyhow};
+use log::info;
+use parasol_cpu::{FheComputer, Memory};
+use parasol_runtime::{Encryption, Evaluation};
+use program_runner::{BYTE_WIDTH_MULTIPLIER_COST, PARAMS, deserialize_parameters, serialize_outputs_to};
+
+use crate::cli::BatchArgs;
+use crate::gas::GasTracker;
+use crate::loader::{decrypt_if_vault, load_compute_key};
+use crate::memory::collect_outputs;
+use crate::processor::{process_parameters, run_program};
+
+/// Run every parameters file in `params_dir` against one loaded program,
+/// amortizing the compute-key/processor setup cost across all records.
+pub(crate) fn batch(args: BatchArgs) -> Result<()> {
+    let elf_bytes = fs::read(&args.elf)
+        .with_context(|| format!("failed to read ELF file '{}'", args.elf.display()))?;
+    Memory::new_from_elf(&elf_bytes)
+        .with_context(|| format!("failed to parse ELF file '{}'", args.elf.display()))?
+        .get_function_entry(&args.func)
+        .ok_or_else(|| {
+            anyhow!(
+                "function '{}' does not exist in ELF file '{}'",
+                args.func,
+                args.elf.display()
+            )
+        })?;
+
+    let compute_key = load_compute_key(&args.key, args.passphrase.as_deref())?;
+    let enc = Encryption::new(&PARAMS);
+    let eval = Evaluation::new(Arc::new(compute_key), &PARAMS, &enc);
+    let mut proc = FheComputer::new(&enc, &eval);
+
+    fs::create_dir_all(&args.output_dir).with_context(|| {
+        format!(
+            "failed to create output directory '{}'",
+            args.output_dir.display()
+        )
+    })?;
+
+    let mut record_paths: Vec<_> = fs::read_dir(&args.params_dir)
+        .with_context(|| format!("failed to read params directory '{}'", args.params_dir.display()))?
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("failed to list params directory '{}'", args.params_dir.display()))?
+        .into_iter()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect();
+    record_paths.sort();
+
+    let mut total_gas = 0u32;
+    for (i, record_path) in record_paths.iter().enumerate() {
+        let parameters_bytes = fs::read(record_path)
+            .with_context(|| format!("failed to read parameters file '{}'", record_path.display()))?;
+        let parameters_bytes = decrypt_if_vault(&parameters_bytes, args.passphrase.as_deref())
+            .with_context(|| format!("failed to decrypt parameters file '{}'", record_path.display()))?;
+        let parameters = deserialize_parameters(&parameters_bytes).with_context(|| {
+            format!(
+                "failed to deserialize parameters from '{}'",
+                record_path.display()
+            )
+        })?;
+
+        // Fresh memory arena and gas tracker per record so no record can see
+        // another's allocations.
+        let memory = Memory::new_from_elf(&elf_bytes)
+            .with_context(|| format!("failed to parse ELF file '{}'", args.elf.display()))?;
+        let func = memory.get_function_entry(&args.func).ok_or_else(|| {
+            anyhow!(
+                "function '{}' does not exist in ELF file '{}'",
+                args.func,
+                args.elf.display()
+            )
+        })?;
+        let memory = Arc::new(memory);
+        let mut gas = GasTracker::new();
+
+        let (args_builder, output_buffers, output_byte_width) =
+            process_parameters(parameters, &mut proc, &memory, &mut gas)?;
+        run_program(&mut proc, func, &memory, args_builder, &mut gas)?;
+        let outputs = collect_outputs(output_buffers, &memory, &mut proc)?;
+        gas.charge(
+            output_byte_width * BYTE_WIDTH_MULTIPLIER_COST,
+            "Result ciphertext packing",
+        );
+        let file_name = record_path
+            .file_name()
+            .ok_or_else(|| anyhow!("params file '{}' has no file name", record_path.display()))?;
+        let output_path = args.output_dir.join(file_name);
+        let mut output_file = fs::File::create(&output_path)
+            .with_context(|| format!("failed to create output file '{}'", output_path.display()))?;
+        serialize_outputs_to(&mut output_file, &outputs).with_context(|| {
+            format!(
+                "failed to serialize output for record '{}'",
+                record_path.display()
+            )
+        })?;
+
+        total_gas += gas.total();
+        info!(
+            "record {}/{}: '{}' consumed {} gas",
+            i + 1,
+            record_paths.len(),
+            record_path.display(),
+            gas.total()
+        );
+    }
+
+    info!(
+        "Processed {} records, total gas consumption {}",
+        record_paths.len(),
+        total_gas
+    );
+    Ok(())
+}
@@ -2,10 +2,43 @@
 
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 
 #[derive(Parser, Debug)]
 pub(crate) struct Args {
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+/// Top-level subcommands covering the full client+runtime workflow.
+#[derive(Subcommand, Debug)]
+pub(crate) enum Command {
+    /// Execute a compiled FHE program against encrypted parameters.
+    Run(RunArgs),
+    /// Generate a secret key, public key, and compute key to files.
+    Keygen(KeygenArgs),
+    /// Encrypt a plaintext value into the wire ciphertext format.
+    Encrypt(EncryptArgs),
+    /// Decrypt a wire-format ciphertext back into a plaintext value.
+    Decrypt(DecryptArgs),
+    /// Build a parameters payload from a JSON specification.
+    PackParams(PackParamsArgs),
+    /// Inspect a serialized output payload.
+    UnpackOutput(UnpackOutputArgs),
+    /// Load the ELF + compute key once and service many jobs over a socket.
+    Serve(ServeArgs),
+    /// Report an ELF function's expected parameter signature as JSON.
+    Inspect(InspectArgs),
+    /// Run one program against many parameter payloads, reusing the loaded key material.
+    Batch(BatchArgs),
+    /// Wrap a key or parameters file in a password-encrypted vault container.
+    Lock(LockArgs),
+    /// Unwrap a password-encrypted vault container back to its plaintext bytes.
+    Unlock(UnlockArgs),
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct RunArgs {
     /// Path to the compiled FHE program ELF file.
     #[arg(short, long)]
     pub elf: PathBuf,
@@ -25,4 +58,186 @@ pub(crate) struct Args {
     /// Output file. If not specified, writes to stdout.
     #[arg(short = 'o', long)]
     pub output: Option<PathBuf>,
+
+    /// Emit the output as an ASCII-armored text envelope instead of raw
+    /// binary, so it survives copy-paste, email, or a JSON string field.
+    #[arg(long)]
+    pub armor: bool,
+
+    /// Passphrase to decrypt the key/parameters files if they are vault
+    /// containers produced by `lock`. Can also be set via `SPF_PASSPHRASE`.
+    #[arg(long, env = "SPF_PASSPHRASE")]
+    pub passphrase: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct KeygenArgs {
+    /// Directory to write the generated secret.key/public.key/compute.key files into.
+    #[arg(short, long)]
+    pub out_dir: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct EncryptArgs {
+    /// Path to the secret key file.
+    #[arg(short, long)]
+    pub secret_key: PathBuf,
+
+    /// Plaintext value to encrypt.
+    #[arg(short, long)]
+    pub value: u64,
+
+    /// Bit width of the encrypted value (8, 16, 32, or 64).
+    #[arg(short, long)]
+    pub bit_width: u32,
+
+    /// Tag the wire-format output as carrying a signed (two's-complement)
+    /// value, so `decrypt` interprets it accordingly without a separate flag.
+    /// `--value` must already be the two's-complement bit pattern for a
+    /// negative number at the chosen bit width.
+    #[arg(long)]
+    pub signed: bool,
+
+    /// Output file for the wire-format ciphertext. If not specified, writes to stdout.
+    #[arg(short = 'o', long)]
+    pub output: Option<PathBuf>,
+
+    /// Passphrase to decrypt the secret key file if it is a vault container
+    /// produced by `lock`. Can also be set via `SPF_PASSPHRASE`.
+    #[arg(long, env = "SPF_PASSPHRASE")]
+    pub passphrase: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct DecryptArgs {
+    /// Path to the secret key file.
+    #[arg(short, long)]
+    pub secret_key: PathBuf,
+
+    /// Path to the wire-format ciphertext file. If not specified, reads from stdin.
+    #[arg(short, long)]
+    pub ciphertext: Option<PathBuf>,
+
+    /// Passphrase to decrypt the secret key file if it is a vault container
+    /// produced by `lock`. Can also be set via `SPF_PASSPHRASE`.
+    #[arg(long, env = "SPF_PASSPHRASE")]
+    pub passphrase: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct PackParamsArgs {
+    /// JSON file describing the parameter entries to pack.
+    #[arg(short, long)]
+    pub spec: PathBuf,
+
+    /// Output parameters file. If not specified, writes to stdout.
+    #[arg(short = 'o', long)]
+    pub output: Option<PathBuf>,
+
+    /// Emit the parameters as an ASCII-armored text envelope instead of raw
+    /// binary, so it survives copy-paste, email, or a JSON string field.
+    #[arg(long)]
+    pub armor: bool,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct UnpackOutputArgs {
+    /// Serialized output payload to inspect. If not specified, reads from stdin.
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct ServeArgs {
+    /// Path to the compiled FHE program ELF file.
+    #[arg(short, long)]
+    pub elf: PathBuf,
+
+    /// Function name to execute within the ELF.
+    #[arg(short, long)]
+    pub func: String,
+
+    /// Path to the compute key file.
+    #[arg(short, long)]
+    pub key: PathBuf,
+
+    /// TCP address to listen on (e.g. `127.0.0.1:7878`).
+    #[arg(short, long, default_value = "127.0.0.1:7878")]
+    pub bind: String,
+
+    /// Passphrase to decrypt the key file if it is a vault container produced
+    /// by `lock`. Can also be set via `SPF_PASSPHRASE`.
+    #[arg(long, env = "SPF_PASSPHRASE")]
+    pub passphrase: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct InspectArgs {
+    /// Path to the compiled FHE program ELF file.
+    #[arg(short, long)]
+    pub elf: PathBuf,
+
+    /// Function name to inspect within the ELF.
+    #[arg(short, long)]
+    pub func: String,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct BatchArgs {
+    /// Path to the compiled FHE program ELF file.
+    #[arg(short, long)]
+    pub elf: PathBuf,
+
+    /// Function name to execute within the ELF.
+    #[arg(short, long)]
+    pub func: String,
+
+    /// Path to the compute key file.
+    #[arg(short, long)]
+    pub key: PathBuf,
+
+    /// Directory containing one parameters file per record. Files are
+    /// processed in sorted filename order.
+    #[arg(short, long)]
+    pub params_dir: PathBuf,
+
+    /// Directory to write one output file per record into, using the same
+    /// file name as the corresponding parameters file.
+    #[arg(short, long)]
+    pub output_dir: PathBuf,
+
+    /// Passphrase to decrypt the key file if it is a vault container produced
+    /// by `lock`. Can also be set via `SPF_PASSPHRASE`.
+    #[arg(long, env = "SPF_PASSPHRASE")]
+    pub passphrase: Option<String>,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct LockArgs {
+    /// File to encrypt, e.g. a compute key or parameters file.
+    #[arg(short, long)]
+    pub input: PathBuf,
+
+    /// Output vault container file. If not specified, writes to stdout.
+    #[arg(short = 'o', long)]
+    pub output: Option<PathBuf>,
+
+    /// Passphrase to encrypt under. Can also be set via `SPF_PASSPHRASE`.
+    #[arg(long, env = "SPF_PASSPHRASE")]
+    pub passphrase: String,
+}
+
+#[derive(Parser, Debug)]
+pub(crate) struct UnlockArgs {
+    /// Vault container file to decrypt.
+    #[arg(short, long)]
+    pub input: PathBuf,
+
+    /// Output plaintext file. If not specified, writes to stdout.
+    #[arg(short = 'o', long)]
+    pub output: Option<PathBuf>,
+
+    /// Passphrase the container was encrypted under. Can also be set via `SPF_PASSPHRASE`.
+    #[arg(long, env = "SPF_PASSPHRASE")]
+    pub passphrase: String,
 }
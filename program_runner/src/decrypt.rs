@@ -0,0 +1,51 @@
+//! `decrypt` subcommand: unwrap a wire-format ciphertext back into a plaintext value.
+
+use std::fs::read;
+
+use anyhow::{Context, Result};
+use parasol_runtime::{Encryption, SecretKey};
+use program_runner::{PARAMS, deserialize_outputs};
+
+use crate::cli::DecryptArgs;
+use crate::io::read_bytes;
+use crate::loader::decrypt_if_vault;
+
+/// Decrypt the single wire-format ciphertext produced by `encrypt` and print its value.
+pub(crate) fn decrypt(args: DecryptArgs) -> Result<()> {
+    let secret_key_bytes = read(&args.secret_key)
+        .with_context(|| format!("failed to read secret key file '{}'", args.secret_key.display()))?;
+    let secret_key_bytes = decrypt_if_vault(&secret_key_bytes, args.passphrase.as_deref())
+        .with_context(|| format!("failed to decrypt secret key file '{}'", args.secret_key.display()))?;
+    let secret_key: SecretKey = rmp_serde::from_slice(&secret_key_bytes).with_context(|| {
+        format!(
+            "failed to deserialize from secret key file '{}'",
+            args.secret_key.display()
+        )
+    })?;
+
+    let (ciphertext_bytes, source) = read_bytes(args.ciphertext.as_deref())?;
+    let outputs = deserialize_outputs(&ciphertext_bytes)
+        .with_context(|| format!("failed to deserialize ciphertext from '{}'", source))?;
+    let ct = outputs
+        .first()
+        .ok_or_else(|| anyhow::anyhow!("ciphertext payload from '{}' is empty", source))?;
+
+    let enc = Encryption::new(&PARAMS);
+    let unsigned: u64 = enc
+        .decrypt_glwe_l1(&ct.ciphertext, &secret_key)
+        .coeffs()
+        .iter()
+        .take(usize::from(ct.bit_width))
+        .enumerate()
+        .map(|(i, &v)| v << i)
+        .sum();
+
+    // The ciphertext's own `signedness` decides the interpretation; the wire
+    // format is self-describing, so `decrypt` never needs a `--signed` flag.
+    if ct.signedness.is_signed() {
+        println!("{}", ct.bit_width.unsigned_to_signed(unsigned));
+    } else {
+        println!("{unsigned}");
+    }
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//! `encrypt` subcommand: wrap a plaintext value into the wire ciphertext format.
+
+use std::fs::read;
+
+use anyhow::{Context, Result};
+use parasol_runtime::{Encryption, SecretKey, fluent::{PackedUInt8, PackedUInt16, PackedUInt32, PackedUInt64}};
+use program_runner::{BitWidth, L1GlweCiphertextWithBitWidth, PARAMS, Signedness, serialize_outputs};
+
+use crate::cli::EncryptArgs;
+use crate::io::write_output;
+use crate::loader::decrypt_if_vault;
+
+/// Encrypt a single plaintext value under a secret key, emitting one wire-format ciphertext.
+pub(crate) fn encrypt(args: EncryptArgs) -> Result<()> {
+    let bit_width = BitWidth::try_from(args.bit_width)
+        .with_context(|| format!("invalid bit width {}", args.bit_width))?;
+
+    let secret_key_bytes = read(&args.secret_key)
+        .with_context(|| format!("failed to read secret key file '{}'", args.secret_key.display()))?;
+    let secret_key_bytes = decrypt_if_vault(&secret_key_bytes, args.passphrase.as_deref())
+        .with_context(|| format!("failed to decrypt secret key file '{}'", args.secret_key.display()))?;
+    let secret_key: SecretKey = rmp_serde::from_slice(&secret_key_bytes).with_context(|| {
+        format!(
+            "failed to deserialize from secret key file '{}'",
+            args.secret_key.display()
+        )
+    })?;
+
+    let max_value = bit_width.max_unsigned();
+    if args.value > max_value {
+        return Err(anyhow::anyhow!(
+            "plaintext value {} exceeds maximum for bit width {} (max: {})",
+            args.value,
+            u8::from(bit_width),
+            max_value
+        ));
+    }
+
+    let enc = Encryption::new(&PARAMS);
+    let ciphertext = match bit_width {
+        BitWidth::U8 => PackedUInt8::encrypt_secret(args.value as u128, &enc, &secret_key).inner(),
+        BitWidth::U16 => PackedUInt16::encrypt_secret(args.value as u128, &enc, &secret_key).inner(),
+        BitWidth::U32 => PackedUInt32::encrypt_secret(args.value as u128, &enc, &secret_key).inner(),
+        BitWidth::U64 => PackedUInt64::encrypt_secret(args.value as u128, &enc, &secret_key).inner(),
+    };
+
+    let signedness = if args.signed {
+        Signedness::Signed
+    } else {
+        Signedness::Unsigned
+    };
+    let outputs = vec![L1GlweCiphertextWithBitWidth {
+        bit_width,
+        ciphertext,
+        signedness,
+        frac_bits: 0,
+    }];
+    let bytes = serialize_outputs(&outputs).context("failed to serialize ciphertext")?;
+    write_output(args.output.as_deref(), &bytes)?;
+
+    Ok(())
+}
@@ -26,9 +26,70 @@ pub enum DeserializeError {
     /// Error deserializing the payload.
     #[error("payload deserialization failed")]
     Payload(#[source] rmp_serde::decode::Error),
+    /// Declared or actual payload size exceeds the caller's `DeserializeLimits`.
+    #[error("size limit exceeded: requested {requested}, limit {limit}")]
+    LimitExceeded { limit: u64, requested: u64 },
+    /// Error reading from the underlying stream (streaming `_from` variants only).
+    #[error("failed to read from stream")]
+    Io(#[source] std::io::Error),
+    /// Error parsing an ASCII-armored text envelope.
+    #[error("armored envelope is malformed: {0}")]
+    Armor(#[from] ArmorError),
+    /// The payload's CRC-32C checksum did not match the one recorded in the header,
+    /// indicating the payload was truncated or corrupted in transit.
+    #[error("checksum mismatch: expected {expected:#010x}, got {got:#010x}")]
+    ChecksumMismatch { expected: u32, got: u32 },
+    /// The payload decoded to a valid msgpack value but left unconsumed bytes
+    /// behind it (e.g. appended garbage, or two concatenated payloads).
+    #[error("{remaining} byte(s) remained after decoding the payload")]
+    TrailingBytes { remaining: usize },
+    /// The HMAC tag on an authenticated blob did not match, indicating the
+    /// wrong key, or tampering/truncation in transit.
+    #[error("authentication tag verification failed: wrong key or tampered data")]
+    AuthenticationFailed,
+}
+
+/// Error type for parsing an ASCII-armored text envelope.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ArmorError {
+    /// The envelope has no header line.
+    #[error("envelope is empty")]
+    Empty,
+    /// The header line isn't `<magic> v<version>`.
+    #[error("malformed header line {0:?}")]
+    MalformedHeader(String),
+    /// The header's magic does not match the expected magic for this payload kind.
+    #[error("invalid magic {got:?}, expected {expected:?}")]
+    InvalidMagic { got: String, expected: String },
+    /// The terminating `---` sentinel line is missing.
+    #[error("missing '---' sentinel line")]
+    MissingSentinel,
+    /// The base64 body could not be decoded.
+    #[error("invalid base64 body: {0}")]
+    InvalidBase64(String),
+}
+
+/// Error type for bulk version-migration operations (`migrate_parameters`/`migrate_outputs`).
+#[derive(Debug, thiserror::Error)]
+pub enum MigrateError {
+    /// Failed to decode the source blob at whatever version it declares.
+    #[error("failed to read source blob: {0}")]
+    Read(#[from] DeserializeError),
+    /// Failed to re-encode the decoded data at the current version.
+    #[error("failed to re-encode at current version: {0}")]
+    Write(#[from] SerializeError),
 }
 
 /// Error type for serialization operations.
 #[derive(Debug, thiserror::Error)]
-#[error("payload serialization failed")]
-pub struct SerializeError(#[source] pub(crate) rmp_serde::encode::Error);
+pub enum SerializeError {
+    /// Error encoding the payload as msgpack.
+    #[error("payload serialization failed")]
+    Payload(#[source] rmp_serde::encode::Error),
+    /// Error writing to the underlying stream (streaming `_to` variants only).
+    #[error("failed to write to stream")]
+    Io(#[source] std::io::Error),
+    /// A [`crate::ParameterEntry`] fragment failed its cheap pre-splice check.
+    #[error("invalid ciphertext fragment: {0}")]
+    InvalidFragment(String),
+}
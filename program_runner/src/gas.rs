@@ -20,4 +20,9 @@ impl GasTracker {
             self.0
         );
     }
+
+    /// Total gas charged so far.
+    pub fn total(&self) -> u32 {
+        self.0
+    }
 }
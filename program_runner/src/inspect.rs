@@ -0,0 +1,13 @@
+//! `inspect` subcommand: report an ELF function's expected parameter signature.
+
+use anyhow::Result;
+
+use crate::cli::InspectArgs;
+use crate::loader::inspect_function_signature;
+
+/// Print the JSON signature of an ELF function to stdout.
+pub(crate) fn inspect(args: InspectArgs) -> Result<()> {
+    let signature = inspect_function_signature(&args.elf, &args.func)?;
+    println!("{}", serde_json::to_string_pretty(&signature)?);
+    Ok(())
+}
@@ -6,75 +6,136 @@ use std::{
     path::Path,
 };
 
-use anyhow::{Context, Result};
-use program_runner::{HEADER_SIZE, peek_parameters_version};
+use anyhow::{Context, Result, anyhow};
+use program_runner::{
+    DeserializeLimits, L1GlweCiphertextWithBitWidth, ParameterType, decrypt_container,
+    deserialize_parameters, deserialize_parameters_from_with_limits, is_armored,
+    is_vault_container, serialize_outputs_to,
+};
 
-/// Read and validate parameters from a file, returning bytes and version.
-fn read_parameters_from_file(path: &Path) -> Result<(Vec<u8>, u32)> {
-    let file = File::open(path)
-        .with_context(|| format!("failed to open parameters file '{}'", path.display()))?;
-    let file_size = file
-        .metadata()
-        .with_context(|| {
-            format!(
-                "failed to get metadata for parameters file '{}'",
-                path.display()
-            )
-        })?
-        .len() as usize;
-    let mut reader = BufReader::new(file);
+/// Bytes peeked from the front of a parameters source, just enough to decide
+/// whether it's a vault container or an armored text envelope without
+/// consuming more of the underlying reader than necessary.
+const PEEK_SIZE: usize = 5;
 
-    let mut header = [0u8; HEADER_SIZE];
-    reader.read_exact(&mut header).with_context(|| {
-        format!(
-            "failed to read header from parameters file '{}'",
-            path.display()
-        )
-    })?;
-    let version = peek_parameters_version(&header)
-        .with_context(|| format!("invalid parameters header in '{}'", path.display()))?;
-
-    let mut buffer = Vec::with_capacity(file_size);
-    buffer.extend_from_slice(&header);
-    reader.read_to_end(&mut buffer).with_context(|| {
-        format!(
-            "failed to read parameters payload from '{}'",
-            path.display()
-        )
+fn decrypt_if_vault(buffer: Vec<u8>, passphrase: Option<&str>) -> Result<Vec<u8>> {
+    if !is_vault_container(&buffer) {
+        return Ok(buffer);
+    }
+    let passphrase = passphrase.ok_or_else(|| {
+        anyhow!("parameters file is password-encrypted; pass --passphrase or set SPF_PASSPHRASE")
     })?;
-    Ok((buffer, version))
+    decrypt_container(&buffer, passphrase).map_err(|e| anyhow!("failed to decrypt vault container: {e}"))
 }
 
-/// Read and validate parameters from stdin, returning bytes and version.
-fn read_parameters_from_stdin() -> Result<(Vec<u8>, u32)> {
-    let stdin = io::stdin();
-    let mut handle = stdin.lock();
-
-    let mut header = [0u8; HEADER_SIZE];
-    handle
-        .read_exact(&mut header)
-        .context("failed to read header from stdin")?;
-    let version =
-        peek_parameters_version(&header).context("invalid parameters header from stdin")?;
+/// Peek up to [`PEEK_SIZE`] bytes from `reader` without losing them, returning
+/// the peeked bytes alongside a reader that will yield them again followed by
+/// the rest of the stream.
+fn peek_front<R: Read>(mut reader: R) -> Result<([u8; PEEK_SIZE], usize, impl Read)> {
+    let mut peeked = [0u8; PEEK_SIZE];
+    let mut filled = 0;
+    while filled < PEEK_SIZE {
+        let n = reader.read(&mut peeked[filled..])?;
+        if n == 0 {
+            break;
+        }
+        filled += n;
+    }
+    let replayed = io::Cursor::new(peeked).take(filled as u64).chain(reader);
+    Ok((peeked, filled, replayed))
+}
 
+/// Read a complete, possibly vault-encrypted or armored, parameters source
+/// into memory and deserialize it. Used for the cases that can't be streamed:
+/// a vault container needs its whole ciphertext before the AEAD tag can be
+/// checked, and an armored envelope needs its whole text before it can be
+/// base64-decoded.
+fn read_whole_and_deserialize<R: Read>(
+    mut reader: R,
+    passphrase: Option<&str>,
+) -> Result<Vec<ParameterType>> {
     let mut buffer = Vec::new();
-    buffer.extend_from_slice(&header);
-    handle
+    reader
         .read_to_end(&mut buffer)
-        .context("failed to read parameters payload from stdin")?;
-    Ok((buffer, version))
+        .context("failed to read parameters payload")?;
+    let buffer =
+        decrypt_if_vault(buffer, passphrase).context("failed to decrypt parameters")?;
+    deserialize_parameters(&buffer).context("failed to deserialize parameters")
+}
+
+/// Read and deserialize parameters from `reader`, streaming the common
+/// plain-binary case directly into `Vec<ParameterType>` without ever holding
+/// the full encoded payload in memory, and falling back to a full read only
+/// for the vault-encrypted/armored cases that inherently require one.
+fn read_and_deserialize_parameters<R: Read>(
+    reader: R,
+    passphrase: Option<&str>,
+) -> Result<Vec<ParameterType>> {
+    let (peeked, filled, mut replayed) = peek_front(reader)?;
+    let peeked = &peeked[..filled];
+    if is_vault_container(peeked) || is_armored(peeked) {
+        return read_whole_and_deserialize(replayed, passphrase);
+    }
+    deserialize_parameters_from_with_limits(&mut replayed, DeserializeLimits::DEFAULT)
+        .context("failed to deserialize parameters")
+}
+
+/// Read and deserialize parameters from a file. Transparently decrypts the
+/// input first if it is a password-encrypted vault container (see
+/// [`program_runner::is_vault_container`]).
+fn read_parameters_from_file(path: &Path, passphrase: Option<&str>) -> Result<Vec<ParameterType>> {
+    let file = File::open(path)
+        .with_context(|| format!("failed to read parameters file '{}'", path.display()))?;
+    read_and_deserialize_parameters(BufReader::new(file), passphrase)
+        .with_context(|| format!("failed to deserialize parameters from '{}'", path.display()))
+}
+
+/// Read and deserialize parameters from stdin. See [`read_parameters_from_file`].
+fn read_parameters_from_stdin(passphrase: Option<&str>) -> Result<Vec<ParameterType>> {
+    read_and_deserialize_parameters(io::stdin().lock(), passphrase)
+        .context("failed to read parameters from stdin")
 }
 
-/// Read parameters from file or stdin, returning bytes, source description, and version.
-pub(crate) fn read_parameters(params_path: Option<&Path>) -> Result<(Vec<u8>, String, u32)> {
+/// Read and deserialize parameters from a file or stdin, returning the
+/// decoded parameters and a source description for error messages.
+///
+/// Transparently decrypts the input first if it is a password-encrypted vault
+/// container (see [`program_runner::is_vault_container`]). The common
+/// plain-binary case is streamed directly from the reader rather than
+/// materialized into an intermediate byte buffer first.
+pub(crate) fn read_parameters(
+    params_path: Option<&Path>,
+    passphrase: Option<&str>,
+) -> Result<(Vec<ParameterType>, String)> {
     match params_path {
         Some(path) => {
-            let (bytes, version) = read_parameters_from_file(path)?;
-            Ok((bytes, path.display().to_string(), version))
+            let parameters = read_parameters_from_file(path, passphrase)?;
+            Ok((parameters, path.display().to_string()))
         }
         None => {
-            let (bytes, version) = read_parameters_from_stdin()?;
-            Ok((bytes, "stdin".to_string(), version))
+            let parameters = read_parameters_from_stdin(passphrase)?;
+            Ok((parameters, "stdin".to_string()))
+        }
+    }
+}
+
+/// Read raw bytes from a file or stdin, with no header validation, returning
+/// bytes and a source description. Used by subcommands that operate on
+/// already-framed blobs (e.g. a single ciphertext) rather than a parameters file.
+pub(crate) fn read_bytes(path: Option<&Path>) -> Result<(Vec<u8>, String)> {
+    match path {
+        Some(path) => {
+            let bytes = std::fs::read(path)
+                .with_context(|| format!("failed to read file '{}'", path.display()))?;
+            Ok((bytes, path.display().to_string()))
+        }
+        None => {
+            let mut buffer = Vec::new();
+            io::stdin()
+                .lock()
+                .read_to_end(&mut buffer)
+                .context("failed to read from stdin")?;
+            Ok((buffer, "stdin".to_string()))
         }
     }
 }
@@ -94,3 +155,26 @@ pub(crate) fn write_output(output_path: Option<&Path>, bytes: &[u8]) -> Result<(
     }
     Ok(())
 }
+
+/// Serialize `outputs` into a file or stdout through [`serialize_outputs_to`].
+/// The encoded payload is still buffered in memory once, to compute the
+/// header checksum, before it is written out.
+pub(crate) fn write_outputs_streaming(
+    output_path: Option<&Path>,
+    outputs: &[L1GlweCiphertextWithBitWidth],
+) -> Result<()> {
+    match output_path {
+        Some(path) => {
+            let mut file = File::create(path)
+                .with_context(|| format!("failed to create output file '{}'", path.display()))?;
+            serialize_outputs_to(&mut file, outputs)
+                .with_context(|| format!("failed to serialize output to '{}'", path.display()))?;
+        }
+        None => {
+            let mut stdout = io::stdout().lock();
+            serialize_outputs_to(&mut stdout, outputs)
+                .context("failed to serialize output to stdout")?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! `keygen` subcommand: generate a secret key, public key, and compute key.
+
+use std::fs::write;
+
+use anyhow::{Context, Result};
+use log::info;
+use parasol_runtime::{ComputeKey, PublicKey, SecretKey};
+use program_runner::PARAMS;
+
+use crate::cli::KeygenArgs;
+
+/// Generate a fresh key set and write each key to its own file in `out_dir`.
+pub(crate) fn keygen(args: KeygenArgs) -> Result<()> {
+    std::fs::create_dir_all(&args.out_dir).with_context(|| {
+        format!(
+            "failed to create output directory '{}'",
+            args.out_dir.display()
+        )
+    })?;
+
+    let secret_key = SecretKey::generate(&PARAMS);
+    let public_key = PublicKey::generate(&PARAMS, &secret_key);
+    let compute_key = ComputeKey::generate(&secret_key, &PARAMS);
+
+    let secret_key_path = args.out_dir.join("secret.key");
+    let public_key_path = args.out_dir.join("public.key");
+    let compute_key_path = args.out_dir.join("compute.key");
+
+    write(
+        &secret_key_path,
+        rmp_serde::to_vec(&secret_key).context("failed to serialize secret key")?,
+    )
+    .with_context(|| format!("failed to write secret key file '{}'", secret_key_path.display()))?;
+
+    write(
+        &public_key_path,
+        rmp_serde::to_vec(&public_key).context("failed to serialize public key")?,
+    )
+    .with_context(|| format!("failed to write public key file '{}'", public_key_path.display()))?;
+
+    write(
+        &compute_key_path,
+        rmp_serde::to_vec(&compute_key).context("failed to serialize compute key")?,
+    )
+    .with_context(|| format!("failed to write compute key file '{}'", compute_key_path.display()))?;
+
+    info!(
+        "Generated key set in '{}': secret.key, public.key, compute.key",
+        args.out_dir.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! Pre-[`Signedness`](crate::types::Signedness) parameter/output schema
+//! (protocol versions 1 and 2), kept only so [`crate::wire::PARAMETERS_MIGRATORS`]/
+//! [`crate::wire::OUTPUT_MIGRATORS`] can still decode blobs written before
+//! version 3 added explicit signedness. Every field maps onto today's schema
+//! with [`Signedness::Unsigned`], preserving the historical implicit
+//! convention those blobs were written under.
+
+use std::num::NonZeroU32;
+
+use parasol_runtime::L1GlweCiphertext;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{BitWidth, L1GlweCiphertextWithBitWidth, ParameterType, Signedness};
+
+/// Ciphertext with associated bit width, as written by versions 1 and 2.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct L1GlweCiphertextWithBitWidthV2 {
+    pub bit_width: BitWidth,
+    pub ciphertext: L1GlweCiphertext,
+}
+
+impl From<L1GlweCiphertextWithBitWidthV2> for L1GlweCiphertextWithBitWidth {
+    fn from(v: L1GlweCiphertextWithBitWidthV2) -> Self {
+        Self {
+            bit_width: v.bit_width,
+            ciphertext: v.ciphertext,
+            signedness: Signedness::Unsigned,
+            frac_bits: 0,
+        }
+    }
+}
+
+/// Parameter types for FHE program inputs, as written by versions 1 and 2.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) enum ParameterTypeV2 {
+    Ciphertext {
+        content: L1GlweCiphertextWithBitWidthV2,
+    },
+    CiphertextArray {
+        contents: Vec<L1GlweCiphertextWithBitWidthV2>,
+    },
+    OutputCiphertextArray {
+        bit_width: BitWidth,
+        size: NonZeroU32,
+    },
+    Plaintext {
+        bit_width: BitWidth,
+        value: u64,
+    },
+    PlaintextArray {
+        bit_width: BitWidth,
+        values: Vec<u64>,
+    },
+}
+
+impl From<ParameterTypeV2> for ParameterType {
+    fn from(v: ParameterTypeV2) -> Self {
+        match v {
+            ParameterTypeV2::Ciphertext { content } => ParameterType::Ciphertext {
+                content: content.into(),
+            },
+            ParameterTypeV2::CiphertextArray { contents } => ParameterType::CiphertextArray {
+                contents: contents.into_iter().map(Into::into).collect(),
+            },
+            ParameterTypeV2::OutputCiphertextArray { bit_width, size } => {
+                ParameterType::OutputCiphertextArray {
+                    bit_width,
+                    size,
+                    signedness: Signedness::Unsigned,
+                }
+            }
+            ParameterTypeV2::Plaintext { bit_width, value } => ParameterType::Plaintext {
+                bit_width,
+                value,
+                signedness: Signedness::Unsigned,
+            },
+            ParameterTypeV2::PlaintextArray { bit_width, values } => ParameterType::PlaintextArray {
+                bit_width,
+                values,
+                signedness: Signedness::Unsigned,
+            },
+        }
+    }
+}
@@ -5,44 +5,81 @@
 //! Parameters and outputs use a versioned binary format:
 //!
 //! ```text
-//! [MAGIC: 4 bytes][VERSION: 4 bytes big-endian u32][PAYLOAD: msgpack bytes]
+//! [MAGIC: 4 bytes][VERSION: 4 bytes big-endian u32][CHECKSUM: 4 bytes big-endian u32][PAYLOAD: msgpack bytes]
 //! ```
 //!
 //! - **MAGIC**: File type identifier ("SPFP" for parameters, "SPFO" for outputs)
 //! - **VERSION**: Protocol version as big-endian u32 (fixed 4 bytes)
+//! - **CHECKSUM**: CRC-32C of the payload bytes, big-endian u32 (fixed 4 bytes).
+//!   Lets a corrupted or truncated payload be rejected with a precise
+//!   `ChecksumMismatch` before `rmp_serde` ever sees it.
 //! - **PAYLOAD**: MessagePack-serialized data
 //!
 //! # Versioning Policy
 //!
-//! This implementation uses strict version matching: the deserializer only
-//! accepts data with an exact version match. This ensures:
-//!
-//! - Predictable behavior across client/server versions
-//! - Early failure on incompatible data rather than silent corruption
-//! - Clear upgrade path when protocol changes
+//! By default, the deserializer is backward-compatible: any version with a
+//! registered decoder in `PARAMETERS_MIGRATORS`/`OUTPUT_MIGRATORS` is accepted
+//! and migrated forward to the current schema. Callers that instead want the
+//! older strict behavior (reject anything but an exact version match) can opt
+//! into it via [`Compatibility::Strict`], e.g. with
+//! `deserialize_parameters_with_compatibility`.
 //!
 //! When protocol changes are needed:
 //! 1. Increment the version constant
-//! 2. Update serialization/deserialization logic
-//! 3. Clients must upgrade to match server version
+//! 2. Add a decoder for the new version to the migrator table
+//! 3. Update serialization logic to write the new version
 
+mod armor;
+mod auth;
 mod error;
+mod legacy;
 mod types;
+mod vault;
 mod wire;
 
-pub use error::{DeserializeError, PeekError, SerializeError};
+pub use armor::is_armored;
+pub use auth::{
+    deserialize_parameters_authenticated, serialize_parameters_authenticated,
+    AUTHENTICATED_PARAMETERS_MAGIC, AUTHENTICATED_PARAMETERS_VERSION,
+};
+pub use error::{ArmorError, DeserializeError, MigrateError, PeekError, SerializeError};
 use parasol_runtime::{DEFAULT_128, Params};
-pub use types::{BitWidth, InvalidBitWidth, L1GlweCiphertextWithBitWidth, ParameterType};
+pub use types::{
+    BitWidth, InvalidBitWidth, L1GlweCiphertextWithBitWidth, ParameterType, Signedness,
+};
+pub use vault::{VaultError, decrypt_container, encrypt_container, is_vault_container};
 pub use wire::{
-    deserialize_outputs, deserialize_parameters, deserialize_parameters_payload,
-    peek_output_version, peek_parameters_version, serialize_outputs, serialize_parameters,
+    Compatibility, DeserializeLimits, deserialize_outputs, deserialize_outputs_allow_trailing,
+    deserialize_outputs_allow_trailing_with_limits, deserialize_outputs_armored,
+    deserialize_outputs_armored_with_compatibility, deserialize_outputs_armored_with_limits,
+    deserialize_outputs_armored_with_limits_and_compatibility, deserialize_outputs_from,
+    deserialize_outputs_from_with_compatibility, deserialize_outputs_from_with_limits,
+    deserialize_outputs_from_with_limits_and_compatibility, deserialize_outputs_with_compatibility,
+    deserialize_outputs_with_limits, deserialize_outputs_with_limits_and_compatibility,
+    deserialize_parameters, deserialize_parameters_allow_trailing,
+    deserialize_parameters_allow_trailing_with_limits, deserialize_parameters_armored,
+    deserialize_parameters_armored_with_compatibility, deserialize_parameters_armored_with_limits,
+    deserialize_parameters_armored_with_limits_and_compatibility, deserialize_parameters_from,
+    deserialize_parameters_from_with_compatibility, deserialize_parameters_from_with_limits,
+    deserialize_parameters_from_with_limits_and_compatibility, deserialize_parameters_payload,
+    deserialize_parameters_payload_with_limits, deserialize_parameters_with_compatibility,
+    deserialize_parameters_with_limits, deserialize_parameters_with_limits_and_compatibility,
+    migrate_outputs, migrate_parameters, peek_output_version, peek_parameters_version,
+    serialize_outputs, serialize_outputs_armored, serialize_outputs_to, serialize_parameter_entries,
+    serialize_parameters, serialize_parameters_armored, serialize_parameters_to, ParameterEntry,
 };
 
 /// Current protocol version for parameters.
-pub const PARAMETERS_VERSION: u32 = 1;
+///
+/// Bumped to 2 when the header gained a trailing CRC-32C checksum field, and
+/// to 3 when [`ParameterType`] gained explicit [`Signedness`] on its
+/// integer-bearing variants. Versions 1 and 2 still load under the default
+/// `Compatibility::Backward` policy via an internal pre-signedness schema,
+/// decoded as `Signedness::Unsigned`.
+pub const PARAMETERS_VERSION: u32 = 3;
 
-/// Current protocol version for outputs.
-pub const OUTPUT_VERSION: u32 = 1;
+/// Current protocol version for outputs. See [`PARAMETERS_VERSION`] for why this is 3.
+pub const OUTPUT_VERSION: u32 = 3;
 
 /// Magic bytes identifying SPF parameter files: "SPFP" in ASCII.
 pub const PARAMETERS_MAGIC: [u8; 4] = *b"SPFP";
@@ -50,9 +87,16 @@ pub const PARAMETERS_MAGIC: [u8; 4] = *b"SPFP";
 /// Magic bytes identifying SPF output files: "SPFO" in ASCII.
 pub const OUTPUT_MAGIC: [u8; 4] = *b"SPFO";
 
-/// Header size: 4 bytes magic + 4 bytes version.
+/// Header size: 4 bytes magic + 4 bytes version. [`peek_parameters_version`]/
+/// [`peek_output_version`] only ever read this much, regardless of checksum layout.
 pub const HEADER_SIZE: usize = 8;
 
+/// Size of the trailing CRC-32C checksum field.
+pub const CHECKSUM_SIZE: usize = 4;
+
+/// Full binary header size: magic + version + checksum, preceding the msgpack payload.
+pub const CHECKSUMMED_HEADER_SIZE: usize = HEADER_SIZE + CHECKSUM_SIZE;
+
 // Gas cost related constants
 pub const BYTE_WIDTH_MULTIPLIER_COST: u32 = 320;
 
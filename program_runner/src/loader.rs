@@ -3,8 +3,11 @@
 use std::{fs::read, path::Path, sync::Arc};
 
 use anyhow::{Context, Result, anyhow};
+use gimli::{DW_TAG_formal_parameter, DW_TAG_pointer_type, DW_TAG_subprogram, Reader};
+use object::{Object, ObjectSection};
 use parasol_cpu::{Memory, Ptr32};
 use parasol_runtime::ComputeKey;
+use program_runner::BitWidth;
 
 /// Load an ELF file and look up a function entry point.
 pub(crate) fn load_elf_function(elf_path: &Path, func_name: &str) -> Result<(Arc<Memory>, Ptr32)> {
@@ -25,9 +28,15 @@ pub(crate) fn load_elf_function(elf_path: &Path, func_name: &str) -> Result<(Arc
 }
 
 /// Load and deserialize a compute key from a file.
-pub(crate) fn load_compute_key(key_path: &Path) -> Result<ComputeKey> {
+///
+/// If the file is a [`program_runner::is_vault_container`] (password-encrypted
+/// with the `lock` subcommand), it is decrypted with `passphrase` first; otherwise the
+/// bytes are read as plaintext, so existing unencrypted key files keep working.
+pub(crate) fn load_compute_key(key_path: &Path, passphrase: Option<&str>) -> Result<ComputeKey> {
     let compute_key_bytes = read(key_path)
         .with_context(|| format!("failed to read key file '{}'", key_path.display()))?;
+    let compute_key_bytes = decrypt_if_vault(&compute_key_bytes, passphrase)
+        .with_context(|| format!("failed to decrypt key file '{}'", key_path.display()))?;
     rmp_serde::from_slice(&compute_key_bytes).with_context(|| {
         format!(
             "failed to deserialize from key file '{}'",
@@ -35,3 +44,408 @@ pub(crate) fn load_compute_key(key_path: &Path) -> Result<ComputeKey> {
         )
     })
 }
+
+/// Decrypt `bytes` with `passphrase` if they are a vault container; otherwise
+/// return them unchanged.
+pub(crate) fn decrypt_if_vault(bytes: &[u8], passphrase: Option<&str>) -> Result<Vec<u8>> {
+    if !program_runner::is_vault_container(bytes) {
+        return Ok(bytes.to_vec());
+    }
+    let passphrase = passphrase
+        .ok_or_else(|| anyhow!("file is password-encrypted; pass --passphrase or set SPF_PASSPHRASE"))?;
+    program_runner::decrypt_container(bytes, passphrase)
+        .map_err(|e| anyhow!("failed to decrypt vault container: {e}"))
+}
+
+/// Expected shape of a single function argument, as inferred from its C type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ArgKind {
+    /// A plain scalar value, e.g. `uint16_t`.
+    Plaintext,
+    /// A pointer-to-scalar parameter, e.g. `uint16_t *`.
+    PlaintextArray,
+    /// A parameter whose name marks it as a write-only result buffer
+    /// (the `out_`/`result_` naming convention used by the Parasol test programs).
+    OutputCiphertextArray,
+}
+
+/// Description of a single expected argument, in declaration order.
+#[derive(Debug, Clone, serde::Serialize)]
+pub(crate) struct ArgSignature {
+    /// Parameter name as it appears in the source.
+    pub name: String,
+    /// Inferred parameter kind.
+    pub kind: ArgKind,
+    /// Bit width of each element.
+    pub bit_width: BitWidth,
+    /// Number of elements; `Some(1)` for scalars, `None` for pointer (array)
+    /// arguments since the array length is not recoverable from the C type
+    /// alone and must come from the caller.
+    pub count: Option<u32>,
+}
+
+/// The expected signature of an ELF function: its ordered arguments and the
+/// summed per-element byte width of any output buffers.
+#[derive(Debug, Clone, serde::Serialize)]
+pub(crate) struct FunctionSignature {
+    pub function: String,
+    pub args: Vec<ArgSignature>,
+    /// Sum, across all output-buffer arguments, of one element's byte width
+    /// (`bit_width.byte_width()`) — *not* the true total allocation, which
+    /// also depends on each buffer's runtime `size` and isn't recoverable
+    /// from DWARF alone (see [`ArgSignature::count`]). Multiply by the
+    /// intended `size` of each output argument to get its real byte count.
+    pub output_byte_width: u32,
+}
+
+/// Walk the DWARF debug info embedded in an ELF file and describe the
+/// parameter list of one function.
+///
+/// This relies on the function having been compiled with debug info (the
+/// Parasol toolchain's default); array length and read/write direction are
+/// not part of the C type system, so arrays are reported without an element
+/// count and output buffers are recognized by the `out_`/`result_` naming
+/// convention used throughout `fhe-programs`. Callers should treat the
+/// result as a best-effort hint for building an `ArgsBuilder`, not a
+/// substitute for validating the actual parameters payload.
+pub(crate) fn inspect_function_signature(
+    elf_path: &Path,
+    func_name: &str,
+) -> Result<FunctionSignature> {
+    let elf_bytes = read(elf_path)
+        .with_context(|| format!("failed to read ELF file '{}'", elf_path.display()))?;
+    let object = object::File::parse(&*elf_bytes)
+        .with_context(|| format!("failed to parse ELF file '{}'", elf_path.display()))?;
+
+    let load_section = |id: gimli::SectionId| -> Result<std::borrow::Cow<[u8]>> {
+        Ok(object
+            .section_by_name(id.name())
+            .and_then(|s| s.uncompressed_data().ok())
+            .unwrap_or_default())
+    };
+    let dwarf_cow = gimli::Dwarf::load(load_section)?;
+    let endian = if object.is_little_endian() {
+        gimli::RunTimeEndian::Little
+    } else {
+        gimli::RunTimeEndian::Big
+    };
+    let dwarf = dwarf_cow.borrow(|section| gimli::EndianSlice::new(section, endian));
+
+    let mut units = dwarf.units();
+    while let Some(header) = units.next()? {
+        let unit = dwarf.unit(header)?;
+        let mut entries = unit.entries();
+        while let Some((_, entry)) = entries.next_dfs()? {
+            if entry.tag() != DW_TAG_subprogram {
+                continue;
+            }
+            let Some(name) = entry_name(&dwarf, &unit, entry)? else {
+                continue;
+            };
+            if name != func_name {
+                continue;
+            }
+            return build_signature(&dwarf, &unit, &mut entries, func_name);
+        }
+    }
+
+    Err(anyhow!(
+        "no debug info found for function '{}' in ELF file '{}'",
+        func_name,
+        elf_path.display()
+    ))
+}
+
+fn entry_name<R: Reader>(
+    dwarf: &gimli::Dwarf<R>,
+    unit: &gimli::Unit<R>,
+    entry: &gimli::DebuggingInformationEntry<R>,
+) -> Result<Option<String>> {
+    let Some(attr) = entry.attr(gimli::DW_AT_name)? else {
+        return Ok(None);
+    };
+    let name = dwarf.attr_string(unit, attr.value())?;
+    Ok(Some(name.to_string_lossy()?.into_owned()))
+}
+
+fn build_signature<R: Reader>(
+    dwarf: &gimli::Dwarf<R>,
+    unit: &gimli::Unit<R>,
+    entries: &mut gimli::EntriesCursor<R>,
+    func_name: &str,
+) -> Result<FunctionSignature> {
+    let mut args = Vec::new();
+    let mut output_byte_width = 0u32;
+
+    while let Some((delta, entry)) = entries.next_dfs()? {
+        if delta <= 0 && entry.tag() != DW_TAG_formal_parameter {
+            break;
+        }
+        if entry.tag() != DW_TAG_formal_parameter {
+            continue;
+        }
+        let name = entry_name(dwarf, unit, entry)?
+            .unwrap_or_else(|| format!("arg{}", args.len()));
+
+        let (bit_width, is_pointer) = resolve_param_type(dwarf, unit, entry)?;
+        let is_output = name.starts_with("out_") || name.starts_with("result_");
+
+        let kind = match (is_pointer, is_output) {
+            (_, true) => ArgKind::OutputCiphertextArray,
+            (true, false) => ArgKind::PlaintextArray,
+            (false, false) => ArgKind::Plaintext,
+        };
+        if kind == ArgKind::OutputCiphertextArray {
+            output_byte_width += bit_width.byte_width();
+        }
+
+        args.push(ArgSignature {
+            name,
+            kind,
+            bit_width,
+            count: if is_pointer { None } else { Some(1) },
+        });
+    }
+
+    Ok(FunctionSignature {
+        function: func_name.to_string(),
+        args,
+        output_byte_width,
+    })
+}
+
+/// Resolve a `DW_TAG_formal_parameter`'s type to a `BitWidth` and whether it is a pointer.
+fn resolve_param_type<R: Reader>(
+    dwarf: &gimli::Dwarf<R>,
+    unit: &gimli::Unit<R>,
+    entry: &gimli::DebuggingInformationEntry<R>,
+) -> Result<(BitWidth, bool)> {
+    let Some(type_attr) = entry.attr(gimli::DW_AT_type)? else {
+        return Ok((BitWidth::U32, false));
+    };
+    let gimli::AttributeValue::UnitRef(offset) = type_attr.value() else {
+        return Ok((BitWidth::U32, false));
+    };
+
+    let mut is_pointer = false;
+    let mut cursor_offset = offset;
+    loop {
+        let type_entry = unit.entry(cursor_offset)?;
+        if type_entry.tag() == DW_TAG_pointer_type {
+            is_pointer = true;
+        }
+        let byte_size = type_entry
+            .attr(gimli::DW_AT_byte_size)?
+            .and_then(|a| a.udata_value())
+            .unwrap_or(4);
+
+        match type_entry.attr(gimli::DW_AT_type)? {
+            Some(attr) => match attr.value() {
+                gimli::AttributeValue::UnitRef(next) => {
+                    cursor_offset = next;
+                    continue;
+                }
+                _ => {
+                    let bit_width = BitWidth::try_from((byte_size as u32) * 8).unwrap_or(BitWidth::U32);
+                    return Ok((bit_width, is_pointer));
+                }
+            },
+            None => {
+                let bit_width = BitWidth::try_from((byte_size as u32) * 8).unwrap_or(BitWidth::U32);
+                return Ok((bit_width, is_pointer));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gimli::{write, Encoding, Format, LittleEndian};
+
+    use super::*;
+
+    /// Build a tiny synthetic DWARF unit by hand (no C toolchain is available
+    /// in this tree to produce a real compiled fixture) describing a function
+    /// with a single `uint16_t *` parameter, and check that `resolve_param_type`
+    /// resolves it to the *pointee*'s bit width (16) rather than stopping at
+    /// the pointer type's own byte size (8/4).
+    #[test]
+    fn resolve_param_type_follows_pointer_to_pointee_width() {
+        let encoding = Encoding {
+            format: Format::Dwarf32,
+            version: 4,
+            address_size: 8,
+        };
+
+        let mut dwarf = write::Dwarf::new();
+        let unit_id = dwarf
+            .units
+            .add(write::Unit::new(encoding, write::LineProgram::none()));
+        let unit = dwarf.units.get_mut(unit_id);
+        let root_id = unit.root();
+
+        let base_type_id = unit.add(root_id, gimli::DW_TAG_base_type);
+        let base_type = unit.get_mut(base_type_id);
+        base_type.set(
+            gimli::DW_AT_name,
+            write::AttributeValue::String(b"uint16_t".to_vec()),
+        );
+        base_type.set(gimli::DW_AT_byte_size, write::AttributeValue::Udata(2));
+
+        let pointer_type_id = unit.add(root_id, DW_TAG_pointer_type);
+        let pointer_type = unit.get_mut(pointer_type_id);
+        pointer_type.set(
+            gimli::DW_AT_type,
+            write::AttributeValue::UnitRef(base_type_id),
+        );
+        pointer_type.set(gimli::DW_AT_byte_size, write::AttributeValue::Udata(8));
+
+        let subprogram_id = unit.add(root_id, DW_TAG_subprogram);
+        let subprogram = unit.get_mut(subprogram_id);
+        subprogram.set(
+            gimli::DW_AT_name,
+            write::AttributeValue::String(b"test_fn".to_vec()),
+        );
+
+        let param_id = unit.add(subprogram_id, DW_TAG_formal_parameter);
+        let param = unit.get_mut(param_id);
+        param.set(
+            gimli::DW_AT_name,
+            write::AttributeValue::String(b"out_arr".to_vec()),
+        );
+        param.set(
+            gimli::DW_AT_type,
+            write::AttributeValue::UnitRef(pointer_type_id),
+        );
+
+        let mut sections = write::Sections::new(write::EndianVec::new(LittleEndian));
+        dwarf.write(&mut sections).expect("failed to write synthetic DWARF");
+
+        let load_section = |id: gimli::SectionId| -> Result<gimli::EndianSlice<LittleEndian>, gimli::Error> {
+            let slice: &[u8] = match id {
+                gimli::SectionId::DebugAbbrev => sections.debug_abbrev.slice(),
+                gimli::SectionId::DebugInfo => sections.debug_info.slice(),
+                gimli::SectionId::DebugStr => sections.debug_str.slice(),
+                gimli::SectionId::DebugLine => sections.debug_line.slice(),
+                _ => &[],
+            };
+            Ok(gimli::EndianSlice::new(slice, LittleEndian))
+        };
+        let read_dwarf = gimli::Dwarf::load(load_section).unwrap();
+
+        let mut unit_headers = read_dwarf.units();
+        let header = unit_headers.next().unwrap().expect("one compilation unit");
+        let read_unit = read_dwarf.unit(header).expect("parseable unit");
+
+        let mut entries = read_unit.entries();
+        let mut result = None;
+        while let Some((_, entry)) = entries.next_dfs().unwrap() {
+            if entry.tag() == DW_TAG_formal_parameter {
+                result = Some(resolve_param_type(&read_dwarf, &read_unit, entry).unwrap());
+                break;
+            }
+        }
+        let (bit_width, is_pointer) = result.expect("formal_parameter DIE present");
+
+        assert!(is_pointer);
+        assert_eq!(bit_width, BitWidth::U16);
+    }
+
+    /// `FunctionSignature::output_byte_width` is documented as the summed
+    /// *per-element* width of each output buffer, not the true allocation
+    /// total (which also needs each buffer's runtime `size`, unavailable
+    /// from DWARF). Build a function with two output buffers of different
+    /// widths and check the field is exactly `sum(bit_width.byte_width())`,
+    /// not that sum scaled by any assumed element count.
+    #[test]
+    fn build_signature_output_byte_width_is_per_element_not_total() {
+        let encoding = Encoding {
+            format: Format::Dwarf32,
+            version: 4,
+            address_size: 8,
+        };
+
+        let mut dwarf = write::Dwarf::new();
+        let unit_id = dwarf
+            .units
+            .add(write::Unit::new(encoding, write::LineProgram::none()));
+        let unit = dwarf.units.get_mut(unit_id);
+        let root_id = unit.root();
+
+        let u16_type_id = unit.add(root_id, gimli::DW_TAG_base_type);
+        unit.get_mut(u16_type_id)
+            .set(gimli::DW_AT_byte_size, write::AttributeValue::Udata(2));
+        let u32_type_id = unit.add(root_id, gimli::DW_TAG_base_type);
+        unit.get_mut(u32_type_id)
+            .set(gimli::DW_AT_byte_size, write::AttributeValue::Udata(4));
+
+        let u16_ptr_id = unit.add(root_id, DW_TAG_pointer_type);
+        unit.get_mut(u16_ptr_id).set(
+            gimli::DW_AT_type,
+            write::AttributeValue::UnitRef(u16_type_id),
+        );
+        let u32_ptr_id = unit.add(root_id, DW_TAG_pointer_type);
+        unit.get_mut(u32_ptr_id).set(
+            gimli::DW_AT_type,
+            write::AttributeValue::UnitRef(u32_type_id),
+        );
+
+        let subprogram_id = unit.add(root_id, DW_TAG_subprogram);
+        unit.get_mut(subprogram_id).set(
+            gimli::DW_AT_name,
+            write::AttributeValue::String(b"test_fn".to_vec()),
+        );
+
+        let out_a_id = unit.add(subprogram_id, DW_TAG_formal_parameter);
+        let out_a = unit.get_mut(out_a_id);
+        out_a.set(
+            gimli::DW_AT_name,
+            write::AttributeValue::String(b"out_a".to_vec()),
+        );
+        out_a.set(gimli::DW_AT_type, write::AttributeValue::UnitRef(u16_ptr_id));
+
+        let out_b_id = unit.add(subprogram_id, DW_TAG_formal_parameter);
+        let out_b = unit.get_mut(out_b_id);
+        out_b.set(
+            gimli::DW_AT_name,
+            write::AttributeValue::String(b"out_b".to_vec()),
+        );
+        out_b.set(gimli::DW_AT_type, write::AttributeValue::UnitRef(u32_ptr_id));
+
+        let mut sections = write::Sections::new(write::EndianVec::new(LittleEndian));
+        dwarf.write(&mut sections).expect("failed to write synthetic DWARF");
+
+        let load_section = |id: gimli::SectionId| -> Result<gimli::EndianSlice<LittleEndian>, gimli::Error> {
+            let slice: &[u8] = match id {
+                gimli::SectionId::DebugAbbrev => sections.debug_abbrev.slice(),
+                gimli::SectionId::DebugInfo => sections.debug_info.slice(),
+                gimli::SectionId::DebugStr => sections.debug_str.slice(),
+                gimli::SectionId::DebugLine => sections.debug_line.slice(),
+                _ => &[],
+            };
+            Ok(gimli::EndianSlice::new(slice, LittleEndian))
+        };
+        let read_dwarf = gimli::Dwarf::load(load_section).unwrap();
+
+        let mut unit_headers = read_dwarf.units();
+        let header = unit_headers.next().unwrap().expect("one compilation unit");
+        let read_unit = read_dwarf.unit(header).expect("parseable unit");
+
+        let mut entries = read_unit.entries();
+        let mut signature = None;
+        while let Some((_, entry)) = entries.next_dfs().unwrap() {
+            if entry.tag() == DW_TAG_subprogram {
+                signature =
+                    Some(build_signature(&read_dwarf, &read_unit, &mut entries, "test_fn").unwrap());
+                break;
+            }
+        }
+        let signature = signature.expect("subprogram DIE present");
+
+        assert_eq!(signature.args.len(), 2);
+        assert!(signature.args.iter().all(|a| a.kind == ArgKind::OutputCiphertextArray));
+        // 2 (U16) + 4 (U32) = 6, *not* e.g. scaled by a runtime array size.
+        assert_eq!(signature.output_byte_width, 6);
+    }
+}
@@ -11,7 +11,7 @@ use parasol_runtime::{
 use program_runner::{
     BitWidth, CIPHERTEXT_UNPACK_BASE_UNIT_COST, CIPHERTEXT_UNPACK_EXPONENTIAL_BASE_COST,
     CIPHERTEXT_UNPACK_MULTIPLIER_COST, CIPHERTEXT_UNPACK_NORMALIZER_COST,
-    L1GlweCiphertextWithBitWidth,
+    L1GlweCiphertextWithBitWidth, Signedness,
 };
 
 use crate::gas::GasTracker;
@@ -24,6 +24,8 @@ pub(crate) struct OutputBuffer {
     pub bit_width: BitWidth,
     /// Number of elements in the output array.
     pub size: NonZeroU32,
+    /// Signedness to tag each packed output element with.
+    pub signedness: Signedness,
 }
 
 /// Unpack a ciphertext with gas tracking.
@@ -155,6 +157,7 @@ pub(crate) fn pack_output_element(
     proc: &mut FheComputer,
     ptr: Ptr32,
     bit_width: BitWidth,
+    signedness: Signedness,
 ) -> Result<L1GlweCiphertextWithBitWidth> {
     let byte_width = bit_width.byte_width();
     let val = memory
@@ -167,6 +170,8 @@ pub(crate) fn pack_output_element(
             .pack_int_dyn(val)
             .context("failed to pack ciphertext")?
             .inner(),
+        signedness,
+        frac_bits: 0,
     })
 }
 
@@ -183,6 +188,7 @@ pub(crate) fn collect_outputs(
         ptr,
         bit_width,
         size,
+        signedness,
     } in output_buffers
     {
         let byte_width = bit_width.byte_width();
@@ -190,7 +196,13 @@ pub(crate) fn collect_outputs(
             let element_ptr = ptr
                 .try_offset(byte_width * i)
                 .context("failed to compute offset for output element")?;
-            outputs.push(pack_output_element(memory, proc, element_ptr, bit_width)?);
+            outputs.push(pack_output_element(
+                memory,
+                proc,
+                element_ptr,
+                bit_width,
+                signedness,
+            )?);
         }
     }
 
@@ -0,0 +1,155 @@
+//! `pack-params`/`unpack-output` subcommands: build and inspect wire payloads.
+
+use std::num::NonZeroU32;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, anyhow};
+use program_runner::{
+    BitWidth, L1GlweCiphertextWithBitWidth, ParameterType, Signedness, deserialize_outputs,
+    serialize_parameters, serialize_parameters_armored,
+};
+use serde::Deserialize;
+
+use crate::cli::{PackParamsArgs, UnpackOutputArgs};
+use crate::io::{read_bytes, write_output};
+
+fn signedness(signed: bool) -> Signedness {
+    if signed {
+        Signedness::Signed
+    } else {
+        Signedness::Unsigned
+    }
+}
+
+/// JSON description of a single parameter entry. Ciphertext entries reference
+/// wire-format ciphertext files (as produced by the `encrypt` subcommand)
+/// rather than embedding ciphertext bytes directly. `signed` defaults to
+/// `false`; the ciphertext entries carry their own signedness on the
+/// referenced wire-format file instead.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ParamEntrySpec {
+    Ciphertext {
+        path: PathBuf,
+    },
+    CiphertextArray {
+        paths: Vec<PathBuf>,
+    },
+    OutputCiphertextArray {
+        bit_width: u32,
+        size: u32,
+        #[serde(default)]
+        signed: bool,
+    },
+    Plaintext {
+        bit_width: u32,
+        value: u64,
+        #[serde(default)]
+        signed: bool,
+    },
+    PlaintextArray {
+        bit_width: u32,
+        values: Vec<u64>,
+        #[serde(default)]
+        signed: bool,
+    },
+}
+
+/// Load the single wire-format ciphertext stored in `path`.
+fn load_ciphertext(path: &PathBuf) -> Result<L1GlweCiphertextWithBitWidth> {
+    let bytes = std::fs::read(path)
+        .with_context(|| format!("failed to read ciphertext file '{}'", path.display()))?;
+    let outputs = deserialize_outputs(&bytes)
+        .with_context(|| format!("failed to deserialize ciphertext file '{}'", path.display()))?;
+    outputs
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("ciphertext file '{}' is empty", path.display()))
+}
+
+fn entry_to_parameter(entry: ParamEntrySpec) -> Result<ParameterType> {
+    Ok(match entry {
+        ParamEntrySpec::Ciphertext { path } => ParameterType::Ciphertext {
+            content: load_ciphertext(&path)?,
+        },
+        ParamEntrySpec::CiphertextArray { paths } => {
+            let contents = paths
+                .iter()
+                .map(load_ciphertext)
+                .collect::<Result<Vec<_>>>()?;
+            ParameterType::CiphertextArray { contents }
+        }
+        ParamEntrySpec::OutputCiphertextArray {
+            bit_width,
+            size,
+            signed,
+        } => ParameterType::OutputCiphertextArray {
+            bit_width: BitWidth::try_from(bit_width)
+                .with_context(|| format!("invalid bit width {bit_width}"))?,
+            size: NonZeroU32::new(size).ok_or_else(|| anyhow!("output size must be at least 1"))?,
+            signedness: signedness(signed),
+        },
+        ParamEntrySpec::Plaintext {
+            bit_width,
+            value,
+            signed,
+        } => ParameterType::Plaintext {
+            bit_width: BitWidth::try_from(bit_width)
+                .with_context(|| format!("invalid bit width {bit_width}"))?,
+            value,
+            signedness: signedness(signed),
+        },
+        ParamEntrySpec::PlaintextArray {
+            bit_width,
+            values,
+            signed,
+        } => ParameterType::PlaintextArray {
+            bit_width: BitWidth::try_from(bit_width)
+                .with_context(|| format!("invalid bit width {bit_width}"))?,
+            values,
+            signedness: signedness(signed),
+        },
+    })
+}
+
+/// Build a parameters payload from a JSON specification of parameter entries.
+pub(crate) fn pack_params(args: PackParamsArgs) -> Result<()> {
+    let spec_bytes = std::fs::read(&args.spec)
+        .with_context(|| format!("failed to read parameter spec '{}'", args.spec.display()))?;
+    let entries: Vec<ParamEntrySpec> = serde_json::from_slice(&spec_bytes)
+        .with_context(|| format!("failed to parse parameter spec '{}'", args.spec.display()))?;
+
+    let parameters = entries
+        .into_iter()
+        .map(entry_to_parameter)
+        .collect::<Result<Vec<_>>>()?;
+
+    if args.armor {
+        let armored =
+            serialize_parameters_armored(&parameters).context("failed to armor parameters")?;
+        write_output(args.output.as_deref(), armored.as_bytes())
+    } else {
+        let bytes = serialize_parameters(&parameters).context("failed to serialize parameters")?;
+        write_output(args.output.as_deref(), &bytes)
+    }
+}
+
+/// Describe a serialized output payload as JSON: one entry per ciphertext with its bit width.
+pub(crate) fn unpack_output(args: UnpackOutputArgs) -> Result<()> {
+    let (bytes, source) = read_bytes(args.output.as_deref())?;
+    let outputs = deserialize_outputs(&bytes)
+        .with_context(|| format!("failed to deserialize output payload from '{}'", source))?;
+
+    let summary: Vec<_> = outputs
+        .iter()
+        .map(|ct| {
+            serde_json::json!({
+                "bit_width": u8::from(ct.bit_width),
+                "signed": ct.signedness.is_signed(),
+            })
+        })
+        .collect();
+
+    println!("{}", serde_json::to_string_pretty(&summary)?);
+    Ok(())
+}
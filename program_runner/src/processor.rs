@@ -5,7 +5,7 @@ use std::sync::Arc;
 
 use anyhow::{Context, Result};
 use parasol_cpu::{ArgsBuilder, FheComputer, Memory, Ptr32, RunProgramOptionsBuilder};
-use program_runner::{BitWidth, L1GlweCiphertextWithBitWidth, ParameterType};
+use program_runner::{BitWidth, L1GlweCiphertextWithBitWidth, ParameterType, Signedness};
 
 use crate::gas::GasTracker;
 use crate::memory::{
@@ -83,10 +83,13 @@ fn process_output_buffer(
     mut acc: Accumulator,
     bit_width: BitWidth,
     size: NonZeroU32,
+    signedness: Signedness,
     memory: &Memory,
 ) -> Result<Accumulator> {
     let byte_width = bit_width.byte_width();
-    let total_byte_width = byte_width * size.get();
+    let total_byte_width = byte_width
+        .checked_mul(size.get())
+        .context("output buffer size overflows byte width calculation")?;
     let ptr = memory
         .try_allocate(total_byte_width)
         .context("memory allocation failure")?;
@@ -97,11 +100,14 @@ fn process_output_buffer(
         ptr,
         bit_width,
         size,
+        signedness,
     });
     Ok(acc)
 }
 
-/// Process a plaintext scalar parameter.
+/// Process a plaintext scalar parameter. `signedness` only records how `value`
+/// should be interpreted by the caller; the bit pattern written into the
+/// argument is identical either way, so it isn't consulted here.
 fn process_plaintext(mut acc: Accumulator, bit_width: BitWidth, value: u64) -> Result<Accumulator> {
     let max_value = bit_width.max_unsigned();
     if value > max_value {
@@ -147,13 +153,17 @@ fn process_param(
         ParameterType::CiphertextArray { contents } => {
             process_ciphertext_array_param(acc, contents, proc, memory, gas)
         }
-        ParameterType::OutputCiphertextArray { bit_width, size } => {
-            process_output_buffer(acc, bit_width, size, memory)
-        }
-        ParameterType::Plaintext { bit_width, value } => process_plaintext(acc, bit_width, value),
-        ParameterType::PlaintextArray { bit_width, values } => {
-            process_plaintext_array_param(acc, bit_width, values, memory)
-        }
+        ParameterType::OutputCiphertextArray {
+            bit_width,
+            size,
+            signedness,
+        } => process_output_buffer(acc, bit_width, size, signedness, memory),
+        ParameterType::Plaintext {
+            bit_width, value, ..
+        } => process_plaintext(acc, bit_width, value),
+        ParameterType::PlaintextArray {
+            bit_width, values, ..
+        } => process_plaintext_array_param(acc, bit_width, values, memory),
     }
 }
 
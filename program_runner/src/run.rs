@@ -0,0 +1,67 @@
+//! `run` subcommand: execute a compiled FHE program against encrypted parameters.
+
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use log::info;
+use parasol_cpu::FheComputer;
+use parasol_runtime::{Encryption, Evaluation};
+use program_runner::{BYTE_WIDTH_MULTIPLIER_COST, PARAMS, serialize_outputs_armored};
+
+use crate::cli::RunArgs;
+use crate::gas::GasTracker;
+use crate::io::{read_parameters, write_output, write_outputs_streaming};
+use crate::loader::{load_compute_key, load_elf_function};
+use crate::memory::collect_outputs;
+use crate::processor::{process_parameters, run_program};
+
+/// Run a compiled FHE program end to end: load, process parameters, execute, and emit outputs.
+pub(crate) fn run(args: RunArgs) -> Result<()> {
+    let mut gas = GasTracker::new();
+
+    // Load ELF program and function entry point
+    let (memory, func) = load_elf_function(&args.elf, &args.func)?;
+    info!(
+        "Successfully loaded function '{}' from ELF file '{}'.",
+        args.func,
+        args.elf.display()
+    );
+
+    // Initialize FHE processor with compute key
+    let compute_key = load_compute_key(&args.key, args.passphrase.as_deref())?;
+    let enc = Encryption::new(&PARAMS);
+    let eval = Evaluation::new(Arc::new(compute_key), &PARAMS, &enc);
+    let mut proc = FheComputer::new(&enc, &eval);
+    info!(
+        "Successfully created processor using key file '{}' and parameters '{:#?}'",
+        args.key.display(),
+        PARAMS
+    );
+
+    // Read and deserialize parameters (transparently accepts the raw binary
+    // wire format or an armored text envelope, and either may be vault-encrypted)
+    let (parameters, _params_source) =
+        read_parameters(args.params.as_deref(), args.passphrase.as_deref())?;
+
+    // Process parameters and build function arguments
+    let (args_builder, output_buffers, output_byte_width) =
+        process_parameters(parameters, &mut proc, &memory, &mut gas)?;
+
+    // Execute FHE program
+    run_program(&mut proc, func, &memory, args_builder, &mut gas)?;
+
+    // Collect and serialize outputs
+    let outputs = collect_outputs(output_buffers, &memory, &mut proc)?;
+    gas.charge(
+        output_byte_width * BYTE_WIDTH_MULTIPLIER_COST,
+        "Result ciphertext packing",
+    );
+    if args.armor {
+        let armored = serialize_outputs_armored(&outputs).context("failed to armor output")?;
+        write_output(args.output.as_deref(), armored.as_bytes())?;
+    } else {
+        write_outputs_streaming(args.output.as_deref(), &outputs)?;
+    }
+
+    Ok(())
+}
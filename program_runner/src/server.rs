@@ -0,0 +1,196 @@
+//! `serve` subcommand: persistent server that loads the ELF + compute key once
+//! and services many jobs over a TCP socket.
+//!
+//! Wire protocol per request: a 4-byte big-endian length prefix followed by a
+//! parameters payload in the same format `read_parameters`/`deserialize_parameters_payload`
+//! already consume. The response is `[tag: 1 byte][gas: 4 bytes BE][len: 4 bytes BE][body]`,
+//! where `tag` is `0` for success (body is the serialized output) or `1` for an error
+//! (body is the UTF-8 error message). A connection stays open and services requests
+//! until the peer disconnects.
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+
+use anyhow::{Context, Result, anyhow};
+use log::{error, info, warn};
+use parasol_cpu::{FheComputer, Memory};
+use parasol_runtime::{Encryption, Evaluation};
+use program_runner::{
+    BYTE_WIDTH_MULTIPLIER_COST, DeserializeLimits, PARAMS, deserialize_parameters_payload,
+    peek_parameters_version, serialize_outputs,
+};
+
+use crate::cli::ServeArgs;
+use crate::gas::GasTracker;
+use crate::loader::load_compute_key;
+use crate::memory::collect_outputs;
+use crate::processor::{process_parameters, run_program};
+
+const FRAME_OK: u8 = 0;
+const FRAME_ERR: u8 = 1;
+
+/// Run the persistent server loop.
+pub(crate) fn serve(args: ServeArgs) -> Result<()> {
+    let elf_bytes = std::fs::read(&args.elf)
+        .with_context(|| format!("failed to read ELF file '{}'", args.elf.display()))?;
+    Memory::new_from_elf(&elf_bytes)
+        .with_context(|| format!("failed to parse ELF file '{}'", args.elf.display()))?
+        .get_function_entry(&args.func)
+        .ok_or_else(|| {
+            anyhow!(
+                "function '{}' does not exist in ELF file '{}'",
+                args.func,
+                args.elf.display()
+            )
+        })?;
+
+    let compute_key = load_compute_key(&args.key, args.passphrase.as_deref())?;
+    let enc = Encryption::new(&PARAMS);
+    let eval = Evaluation::new(Arc::new(compute_key), &PARAMS, &enc);
+    let mut proc = FheComputer::new(&enc, &eval);
+    info!(
+        "Loaded ELF '{}' and key '{}'; listening on '{}'",
+        args.elf.display(),
+        args.key.display(),
+        args.bind
+    );
+
+    let listener = TcpListener::bind(&args.bind)
+        .with_context(|| format!("failed to bind to '{}'", args.bind))?;
+
+    for stream in listener.incoming() {
+        let mut stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                warn!("failed to accept connection: {e}");
+                continue;
+            }
+        };
+        if let Err(e) = handle_connection(&mut stream, &elf_bytes, &args.func, &mut proc) {
+            error!("connection handling failed: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Service length-prefixed requests on one connection until the peer disconnects.
+fn handle_connection(
+    stream: &mut TcpStream,
+    elf_bytes: &[u8],
+    func_name: &str,
+    proc: &mut FheComputer,
+) -> Result<()> {
+    loop {
+        let mut len_buf = [0u8; 4];
+        if stream.read_exact(&mut len_buf).is_err() {
+            return Ok(());
+        }
+        let len = u32::from_be_bytes(len_buf) as usize;
+        if let Some(max_bytes) = DeserializeLimits::DEFAULT.max_bytes {
+            if len as u64 > max_bytes {
+                // The oversized payload itself is still sitting unread on the
+                // socket, so there's no way to stay in sync with the next
+                // frame; report the error and drop the connection rather than
+                // risk misinterpreting the remaining bytes as a new request.
+                write_frame(
+                    stream,
+                    FRAME_ERR,
+                    0,
+                    format!("request payload of {len} bytes exceeds the {max_bytes}-byte limit")
+                        .as_bytes(),
+                )?;
+                return Ok(());
+            }
+        }
+        let mut payload = vec![0u8; len];
+        stream
+            .read_exact(&mut payload)
+            .context("failed to read request payload")?;
+
+        // A malformed-but-within-limits payload (e.g. an output array size
+        // that overflows a byte-width calculation downstream) should fail
+        // this one request, not take down every other connection the server
+        // is servicing; catch_unwind contains a panic to this job.
+        let job =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                run_job(elf_bytes, func_name, proc, &payload)
+            }));
+        match job {
+            Ok(Ok((output_bytes, gas_used))) => {
+                write_frame(stream, FRAME_OK, gas_used, &output_bytes)?
+            }
+            Ok(Err(e)) => write_frame(stream, FRAME_ERR, 0, e.to_string().as_bytes())?,
+            Err(panic) => {
+                let message = panic_message(&panic);
+                error!("job panicked: {message}");
+                write_frame(
+                    stream,
+                    FRAME_ERR,
+                    0,
+                    format!("internal error processing request: {message}").as_bytes(),
+                )?;
+            }
+        }
+    }
+}
+
+/// Recover a human-readable message from a `catch_unwind` panic payload,
+/// covering the two shapes `std::panic!`/`.unwrap()` actually produce.
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Execute one job against a freshly allocated memory arena and gas tracker.
+fn run_job(
+    elf_bytes: &[u8],
+    func_name: &str,
+    proc: &mut FheComputer,
+    payload: &[u8],
+) -> Result<(Vec<u8>, u32)> {
+    let memory = Memory::new_from_elf(elf_bytes).context("failed to parse ELF file")?;
+    let func = memory
+        .get_function_entry(func_name)
+        .ok_or_else(|| anyhow!("function '{func_name}' does not exist in ELF file"))?;
+    let memory = Arc::new(memory);
+    let mut gas = GasTracker::new();
+
+    let version = peek_parameters_version(payload).context("invalid parameters header")?;
+    let parameters = deserialize_parameters_payload(payload, version)
+        .context("failed to deserialize parameters")?;
+
+    let (args_builder, output_buffers, output_byte_width) =
+        process_parameters(parameters, proc, &memory, &mut gas)?;
+    run_program(proc, func, &memory, args_builder, &mut gas)?;
+    let outputs = collect_outputs(output_buffers, &memory, proc)?;
+    gas.charge(
+        output_byte_width * BYTE_WIDTH_MULTIPLIER_COST,
+        "Result ciphertext packing",
+    );
+    let output_bytes = serialize_outputs(&outputs).context("failed to serialize output")?;
+    Ok((output_bytes, gas.total()))
+}
+
+fn write_frame(stream: &mut TcpStream, tag: u8, gas_used: u32, body: &[u8]) -> Result<()> {
+    stream
+        .write_all(&[tag])
+        .context("failed to write response tag")?;
+    stream
+        .write_all(&gas_used.to_be_bytes())
+        .context("failed to write gas frame")?;
+    stream
+        .write_all(&(body.len() as u32).to_be_bytes())
+        .context("failed to write response length")?;
+    stream
+        .write_all(body)
+        .context("failed to write response body")?;
+    stream.flush().context("failed to flush response")?;
+    Ok(())
+}
@@ -39,6 +39,26 @@ impl BitWidth {
         }
     }
 
+    /// Get the maximum signed value for this bit width.
+    pub fn max_signed(self) -> i64 {
+        match self {
+            BitWidth::U8 => i8::MAX as i64,
+            BitWidth::U16 => i16::MAX as i64,
+            BitWidth::U32 => i32::MAX as i64,
+            BitWidth::U64 => i64::MAX,
+        }
+    }
+
+    /// Get the minimum signed value for this bit width.
+    pub fn min_signed(self) -> i64 {
+        match self {
+            BitWidth::U8 => i8::MIN as i64,
+            BitWidth::U16 => i16::MIN as i64,
+            BitWidth::U32 => i32::MIN as i64,
+            BitWidth::U64 => i64::MIN,
+        }
+    }
+
     /// Convert a signed value to its unsigned representation using two's complement.
     pub fn signed_to_unsigned(self, value: i64) -> u64 {
         match self {
@@ -106,6 +126,29 @@ impl From<BitWidth> for usize {
     }
 }
 
+/// Explicit signedness of an integer-valued parameter or output.
+///
+/// Previously signedness was out-of-band convention: callers had to separately
+/// remember to call [`BitWidth::signed_to_unsigned`]/[`BitWidth::unsigned_to_signed`]
+/// on the way in and out, and nothing on the wire recorded which interpretation
+/// was intended. Carrying it alongside the value instead makes the operand
+/// self-describing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Signedness {
+    /// The value is the plain unsigned bit pattern.
+    #[default]
+    Unsigned,
+    /// The value is a two's-complement signed bit pattern.
+    Signed,
+}
+
+impl Signedness {
+    /// `true` if this is [`Signedness::Signed`].
+    pub fn is_signed(self) -> bool {
+        matches!(self, Signedness::Signed)
+    }
+}
+
 /// Parameter types for FHE program inputs.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ParameterType {
@@ -121,21 +164,35 @@ pub enum ParameterType {
     OutputCiphertextArray {
         bit_width: BitWidth,
         size: NonZeroU32,
+        signedness: Signedness,
     },
     /// Single plaintext parameter
-    Plaintext { bit_width: BitWidth, value: u64 },
+    Plaintext {
+        bit_width: BitWidth,
+        value: u64,
+        signedness: Signedness,
+    },
     /// Array of plaintext parameters
     PlaintextArray {
         bit_width: BitWidth,
         values: Vec<u64>,
+        signedness: Signedness,
     },
 }
 
-/// Ciphertext with associated bit width.
+/// Ciphertext with associated bit width and signedness.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct L1GlweCiphertextWithBitWidth {
     pub bit_width: BitWidth,
     pub ciphertext: L1GlweCiphertext,
+    pub signedness: Signedness,
+    /// Fixed-point fractional bits: `0` for a plain integer ciphertext, or
+    /// the number of low bits of the decrypted integer that represent the
+    /// fractional part, so the encoded real number is `value / 2^frac_bits`.
+    /// Defaults to `0` on read so payloads written before this field existed
+    /// decode unchanged, as a plain integer.
+    #[serde(default)]
+    pub frac_bits: u8,
 }
 
 impl std::fmt::Debug for L1GlweCiphertextWithBitWidth {
@@ -143,6 +200,8 @@ impl std::fmt::Debug for L1GlweCiphertextWithBitWidth {
         f.debug_struct("L1GlweCiphertextWithBitWidth")
             .field("bit_width", &self.bit_width)
             .field("ciphertext", &"<L1GlweCiphertext>")
+            .field("signedness", &self.signedness)
+            .field("frac_bits", &self.frac_bits)
             .finish()
     }
 }
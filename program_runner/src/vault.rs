@@ -0,0 +1,136 @@
+//! Optional password-encrypted container for key and parameter files at rest.
+//!
+//! # Container Format
+//!
+//! ```text
+//! [MAGIC: 4 bytes]["SPFV"][VERSION: 4 bytes BE][SALT: 16 bytes][NONCE: 12 bytes][CIPHERTEXT + 16-byte AEAD tag]
+//! ```
+//!
+//! A 256-bit key is derived from a user-supplied passphrase with Argon2id over
+//! the random salt, and the body is sealed with ChaCha20-Poly1305 under the
+//! random nonce. Callers should treat the absence of the magic bytes as "this
+//! file is plaintext" and fall back to reading it unencrypted, so existing
+//! unencrypted keys and parameter files keep working.
+
+use argon2::Argon2;
+use chacha20poly1305::{
+    ChaCha20Poly1305, Key, Nonce,
+    aead::{Aead, KeyInit},
+};
+use rand::{RngCore, rng};
+
+/// Magic bytes identifying an encrypted vault container: "SPFV" in ASCII.
+pub const VAULT_MAGIC: [u8; 4] = *b"SPFV";
+
+/// Current vault container version.
+pub const VAULT_VERSION: u32 = 1;
+
+const SALT_SIZE: usize = 16;
+const NONCE_SIZE: usize = 12;
+const VAULT_HEADER_SIZE: usize = 4 + 4 + SALT_SIZE + NONCE_SIZE;
+
+/// Error type for vault container operations.
+#[derive(Debug, thiserror::Error)]
+pub enum VaultError {
+    /// Data is too short to contain a valid vault header.
+    #[error("data too short to contain a valid vault header")]
+    TooShort,
+    /// Magic bytes do not match the vault magic.
+    #[error("not a vault container")]
+    NotAVault,
+    /// Vault version is not supported.
+    #[error("unsupported vault version {got}, expected {expected}")]
+    UnsupportedVersion { got: u32, expected: u32 },
+    /// Passphrase derivation failed.
+    #[error("key derivation failed: {0}")]
+    KeyDerivation(String),
+    /// AEAD decryption failed (wrong passphrase or corrupted/tampered data).
+    #[error("failed to decrypt vault: wrong passphrase or corrupted data")]
+    Decrypt,
+}
+
+/// Returns true if `bytes` begins with the vault magic.
+pub fn is_vault_container(bytes: &[u8]) -> bool {
+    bytes.len() >= 4 && bytes[0..4] == VAULT_MAGIC
+}
+
+fn derive_key(passphrase: &str, salt: &[u8; SALT_SIZE]) -> Result<Key, VaultError> {
+    let mut key_bytes = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key_bytes)
+        .map_err(|e| VaultError::KeyDerivation(e.to_string()))?;
+    Ok(Key::from(key_bytes))
+}
+
+/// Encrypt `plaintext` under `passphrase`, producing a self-contained vault container.
+pub fn encrypt_container(plaintext: &[u8], passphrase: &str) -> Result<Vec<u8>, VaultError> {
+    let mut salt = [0u8; SALT_SIZE];
+    let mut nonce_bytes = [0u8; NONCE_SIZE];
+    rng().fill_bytes(&mut salt);
+    rng().fill_bytes(&mut nonce_bytes);
+
+    let key = derive_key(passphrase, &salt)?;
+    let cipher = ChaCha20Poly1305::new(&key);
+    let nonce = Nonce::from(nonce_bytes);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|_| VaultError::Decrypt)?;
+
+    let mut out = Vec::with_capacity(VAULT_HEADER_SIZE + ciphertext.len());
+    out.extend_from_slice(&VAULT_MAGIC);
+    out.extend_from_slice(&VAULT_VERSION.to_be_bytes());
+    out.extend_from_slice(&salt);
+    out.extend_from_slice(&nonce_bytes);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+/// Decrypt a vault container produced by [`encrypt_container`] using `passphrase`.
+pub fn decrypt_container(bytes: &[u8], passphrase: &str) -> Result<Vec<u8>, VaultError> {
+    if bytes.len() < VAULT_HEADER_SIZE {
+        return Err(VaultError::TooShort);
+    }
+    if bytes[0..4] != VAULT_MAGIC {
+        return Err(VaultError::NotAVault);
+    }
+    let version = u32::from_be_bytes(bytes[4..8].try_into().unwrap());
+    if version != VAULT_VERSION {
+        return Err(VaultError::UnsupportedVersion {
+            got: version,
+            expected: VAULT_VERSION,
+        });
+    }
+
+    let salt: [u8; SALT_SIZE] = bytes[8..8 + SALT_SIZE].try_into().unwrap();
+    let nonce_bytes: [u8; NONCE_SIZE] =
+        bytes[8 + SALT_SIZE..VAULT_HEADER_SIZE].try_into().unwrap();
+    let ciphertext = &bytes[VAULT_HEADER_SIZE..];
+
+    let key = derive_key(passphrase, &salt)?;
+    let cipher = ChaCha20Poly1305::new(&key);
+    let nonce = Nonce::from(nonce_bytes);
+    cipher
+        .decrypt(&nonce, ciphertext)
+        .map_err(|_| VaultError::Decrypt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_under_the_correct_passphrase() {
+        let plaintext = b"top secret compute key bytes";
+        let container = encrypt_container(plaintext, "hunter2").unwrap();
+        assert!(is_vault_container(&container));
+        let decrypted = decrypt_container(&container, "hunter2").unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn rejects_wrong_passphrase() {
+        let container = encrypt_container(b"top secret compute key bytes", "hunter2").unwrap();
+        let err = decrypt_container(&container, "wrong-passphrase").unwrap_err();
+        assert!(matches!(err, VaultError::Decrypt));
+    }
+}
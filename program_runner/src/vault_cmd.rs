@@ -0,0 +1,24 @@
+//! `lock`/`unlock` subcommands: wrap/unwrap a key or parameters file in a
+//! password-encrypted vault container (see [`program_runner::encrypt_container`]).
+
+use anyhow::{Context, Result};
+use program_runner::{decrypt_container, encrypt_container};
+
+use crate::cli::{LockArgs, UnlockArgs};
+use crate::io::{read_bytes, write_output};
+
+/// Encrypt a plaintext file into a vault container under a passphrase.
+pub(crate) fn lock(args: LockArgs) -> Result<()> {
+    let (plaintext, source) = read_bytes(Some(&args.input))?;
+    let container = encrypt_container(&plaintext, &args.passphrase)
+        .with_context(|| format!("failed to encrypt '{source}'"))?;
+    write_output(args.output.as_deref(), &container)
+}
+
+/// Decrypt a vault container back into its plaintext bytes.
+pub(crate) fn unlock(args: UnlockArgs) -> Result<()> {
+    let (container, source) = read_bytes(Some(&args.input))?;
+    let plaintext = decrypt_container(&container, &args.passphrase)
+        .with_context(|| format!("failed to decrypt '{source}'"))?;
+    write_output(args.output.as_deref(), &plaintext)
+}
@@ -1,25 +1,153 @@
 //! Serialization and deserialization for parameters and outputs.
 
+use std::io::{Read, Write};
+
+use serde::de::Deserialize as _;
 use serde::Serialize;
 
-use crate::error::{DeserializeError, PeekError, SerializeError};
-use crate::types::{L1GlweCiphertextWithBitWidth, ParameterType};
-use crate::{HEADER_SIZE, OUTPUT_MAGIC, OUTPUT_VERSION, PARAMETERS_MAGIC, PARAMETERS_VERSION};
+use crate::armor;
+use crate::error::{ArmorError, DeserializeError, MigrateError, PeekError, SerializeError};
+use crate::legacy::{L1GlweCiphertextWithBitWidthV2, ParameterTypeV2};
+use crate::types::{BitWidth, L1GlweCiphertextWithBitWidth, ParameterType};
+use crate::{
+    CHECKSUM_SIZE, CHECKSUMMED_HEADER_SIZE, HEADER_SIZE, OUTPUT_MAGIC, OUTPUT_VERSION,
+    PARAMETERS_MAGIC, PARAMETERS_VERSION,
+};
+
+/// Size-limit policy for deserializing parameters/outputs from untrusted
+/// sources, modeled on bincode's `Bounded`/`Infinite` limit configs. Checked
+/// against the declared payload size (and, where recoverable without a full
+/// decode, the top-level element count) before `rmp_serde` is asked to
+/// allocate anything, so a malicious header can't force a giant allocation.
+#[derive(Debug, Clone, Copy)]
+pub struct DeserializeLimits {
+    /// Maximum payload size in bytes, header excluded. `None` means unbounded.
+    pub max_bytes: Option<u64>,
+    /// Maximum number of top-level elements (array entries). `None` means unbounded.
+    pub max_elements: Option<usize>,
+    /// Maximum encoded size in bytes of any single top-level element (e.g. one
+    /// `Ciphertext` parameter entry). `None` means unbounded. Catches a single
+    /// oversized ciphertext hiding inside an otherwise-reasonable array, which
+    /// `max_elements` alone would miss.
+    pub max_element_bytes: Option<u64>,
+}
+
+impl DeserializeLimits {
+    /// No size or element cap. Use only for trusted, locally-produced data.
+    pub const UNBOUNDED: Self = Self {
+        max_bytes: None,
+        max_elements: None,
+        max_element_bytes: None,
+    };
+
+    /// The cap applied by default by `deserialize_parameters`/`deserialize_outputs`:
+    /// generous enough for any legitimate workload while still bounding how much
+    /// a hostile file can force this process to allocate. `max_element_bytes` is
+    /// sized well above the encoded size of a single ciphertext under [`crate::PARAMS`].
+    pub const DEFAULT: Self = Self {
+        max_bytes: Some(256 * 1024 * 1024),
+        max_elements: Some(1_000_000),
+        max_element_bytes: Some(16 * 1024 * 1024),
+    };
+}
+
+/// Version-matching policy for `deserialize_parameters`/`deserialize_outputs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compatibility {
+    /// Only the exact current version ([`PARAMETERS_VERSION`]/[`OUTPUT_VERSION`])
+    /// is accepted; anything else is `DeserializeError::UnsupportedVersion`,
+    /// even if a migrator for it is registered.
+    Strict,
+    /// Any version with a decoder registered in [`PARAMETERS_MIGRATORS`]/
+    /// [`OUTPUT_MIGRATORS`] is accepted and migrated forward to the current
+    /// schema. This is the default: it's how `deserialize_parameters`/
+    /// `deserialize_outputs` already behave, so opting into `Strict` (rather
+    /// than opting into migration) is the one callers have to ask for.
+    #[default]
+    Backward,
+}
+
+/// Check `payload` (the msgpack bytes, header already stripped) against `limits`.
+///
+/// The element count is recovered by peeking the msgpack array-length prefix
+/// without decoding the elements themselves, so this stays cheap even for a
+/// payload that fails the check.
+pub(crate) fn check_limits(payload: &[u8], limits: DeserializeLimits) -> Result<(), DeserializeError> {
+    if let Some(max_bytes) = limits.max_bytes {
+        let requested = payload.len() as u64;
+        if requested > max_bytes {
+            return Err(DeserializeError::LimitExceeded {
+                limit: max_bytes,
+                requested,
+            });
+        }
+    }
+    let mut cursor = std::io::Cursor::new(payload);
+    let Ok(len) = rmp::decode::read_array_len(&mut cursor) else {
+        return Ok(());
+    };
+    if let Some(max_elements) = limits.max_elements {
+        let requested = u64::from(len);
+        if requested > max_elements as u64 {
+            return Err(DeserializeError::LimitExceeded {
+                limit: max_elements as u64,
+                requested,
+            });
+        }
+    }
+    if let Some(max_element_bytes) = limits.max_element_bytes {
+        check_element_sizes(&mut cursor, len, max_element_bytes)?;
+    }
+    Ok(())
+}
+
+/// Walk the remaining `len` top-level array elements starting at `cursor`'s
+/// current position, measuring each element's encoded size without
+/// materializing it (each element is skipped via `serde::de::IgnoredAny`
+/// rather than deserialized into its real type), and fail fast on the first
+/// one that exceeds `max_element_bytes`.
+fn check_element_sizes(
+    cursor: &mut std::io::Cursor<&[u8]>,
+    len: u32,
+    max_element_bytes: u64,
+) -> Result<(), DeserializeError> {
+    for _ in 0..len {
+        let before = cursor.position();
+        serde::de::IgnoredAny::deserialize(&mut rmp_serde::Deserializer::new(&mut *cursor))
+            .map_err(DeserializeError::Payload)?;
+        let element_size = cursor.position() - before;
+        if element_size > max_element_bytes {
+            return Err(DeserializeError::LimitExceeded {
+                limit: max_element_bytes,
+                requested: element_size,
+            });
+        }
+    }
+    Ok(())
+}
 
 /// Peek the version number from parameter bytes without full deserialization.
 ///
-/// This reads only the header (magic bytes + version) to allow fast-fail
-/// for unsupported versions without deserializing the entire payload.
+/// This reads only the header (magic bytes + version) to allow fast-fail for
+/// unsupported versions without deserializing the entire payload. Transparently
+/// accepts either the raw binary header or an [`armor`]-ed text envelope.
 pub fn peek_parameters_version(bytes: &[u8]) -> Result<u32, PeekError> {
     peek_version(bytes, &PARAMETERS_MAGIC)
 }
 
 /// Peek the version number from output bytes without full deserialization.
+/// Transparently accepts either the raw binary header or an armored envelope.
 pub fn peek_output_version(bytes: &[u8]) -> Result<u32, PeekError> {
     peek_version(bytes, &OUTPUT_MAGIC)
 }
 
 fn peek_version(bytes: &[u8], expected_magic: &[u8; 4]) -> Result<u32, PeekError> {
+    if bytes.len() < 4 {
+        return Err(PeekError::TooShort);
+    }
+    if armor::is_armored(bytes) {
+        return armor::peek_armored_version(bytes, expected_magic);
+    }
     if bytes.len() < HEADER_SIZE {
         return Err(PeekError::TooShort);
     }
@@ -49,48 +177,1020 @@ fn serialize_with_header<T: Serialize + ?Sized>(
     version: u32,
     payload: &T,
 ) -> Result<Vec<u8>, SerializeError> {
-    let mut buf = Vec::with_capacity(HEADER_SIZE);
+    let payload_bytes = rmp_serde::to_vec(payload).map_err(SerializeError::Payload)?;
+    Ok(frame_payload(magic, version, &payload_bytes))
+}
+
+/// Prepend the magic/version/checksum header to an already-encoded `payload_bytes`.
+fn frame_payload(magic: &[u8; 4], version: u32, payload_bytes: &[u8]) -> Vec<u8> {
+    let checksum = crc32c::crc32c(payload_bytes);
+    let mut buf = Vec::with_capacity(CHECKSUMMED_HEADER_SIZE + payload_bytes.len());
     buf.extend_from_slice(magic);
     buf.extend_from_slice(&version.to_be_bytes());
-    let payload_bytes = rmp_serde::to_vec(payload).map_err(SerializeError)?;
-    buf.extend_from_slice(&payload_bytes);
+    buf.extend_from_slice(&checksum.to_be_bytes());
+    buf.extend_from_slice(payload_bytes);
+    buf
+}
+
+/// A parameter entry ready for serialization by [`serialize_parameter_entries`]:
+/// either a value to encode normally, or an already-encoded `Ciphertext`/
+/// `CiphertextArray` entry to splice into the payload verbatim.
+///
+/// [`ParameterType::Ciphertext`] and [`ParameterType::CiphertextArray`] carry
+/// the largest data in a parameter set. A caller that already holds a
+/// complete encoded entry — e.g. one extracted from a previously-built
+/// payload, or cached from an earlier `serialize_parameter_entries` call for
+/// a ciphertext reused across several parameter sets — can pass it as a
+/// `Fragment` instead of `Value` to skip re-encoding it. The fragment is still
+/// decoded once by [`validate_fragment`] to check it before it is spliced in;
+/// only the encoding half of the round trip is saved.
+pub enum ParameterEntry {
+    /// A parameter encoded normally.
+    Value(ParameterType),
+    /// A complete pre-encoded `ParameterType::Ciphertext` entry. `raw` must be
+    /// exactly what `rmp_serde` would produce for that entry (e.g. bytes
+    /// previously extracted from a `serialize_parameters`/
+    /// `serialize_parameter_entries` payload), not just the inner ciphertext;
+    /// `bit_width` is the width it was validated against when first decoded.
+    CiphertextFragment { bit_width: BitWidth, raw: Vec<u8> },
+    /// Same, for a complete pre-encoded `ParameterType::CiphertextArray` entry.
+    CiphertextArrayFragment { bit_width: BitWidth, raw: Vec<u8> },
+}
+
+/// Serialize `entries` to the parameters wire format, splicing any
+/// [`ParameterEntry::CiphertextFragment`]/[`ParameterEntry::CiphertextArrayFragment`]
+/// bytes directly into the payload instead of decoding and re-encoding them.
+///
+/// Each fragment is validated by decoding just that one entry (not the rest
+/// of `entries`, and not the full payload this function assembles) and
+/// checking its embedded bit width against the `bit_width` the caller
+/// claims it was validated against; see [`validate_fragment`].
+pub fn serialize_parameter_entries(entries: &[ParameterEntry]) -> Result<Vec<u8>, SerializeError> {
+    let payload_bytes = encode_parameter_entries(entries)?;
+    Ok(frame_payload(&PARAMETERS_MAGIC, PARAMETERS_VERSION, &payload_bytes))
+}
+
+fn encode_parameter_entries(entries: &[ParameterEntry]) -> Result<Vec<u8>, SerializeError> {
+    let mut buf = Vec::new();
+    rmp::encode::write_array_len(&mut buf, entries.len() as u32)
+        .expect("writing to an in-memory Vec<u8> cannot fail");
+    for entry in entries {
+        match entry {
+            ParameterEntry::Value(param) => {
+                param
+                    .serialize(&mut rmp_serde::Serializer::new(&mut buf))
+                    .map_err(SerializeError::Payload)?;
+            }
+            ParameterEntry::CiphertextFragment { bit_width, raw }
+            | ParameterEntry::CiphertextArrayFragment { bit_width, raw } => {
+                validate_fragment(*bit_width, raw)?;
+                buf.extend_from_slice(raw);
+            }
+        }
+    }
     Ok(buf)
 }
 
+/// Validate a [`ParameterEntry`] fragment before splicing its raw bytes into
+/// the payload: check it isn't empty, decode it as a standalone
+/// `ParameterType` entry, and confirm its embedded bit width matches the
+/// `bit_width` the caller claims `raw` was validated against. This decodes
+/// only the one fragment being spliced, not the other entries in the
+/// payload `serialize_parameter_entries` is assembling, so it's still far
+/// cheaper than the full round trip `ParameterEntry::Value` would pay for
+/// every entry. A caller that cannot vouch for `raw` being a validly-encoded
+/// entry should use [`ParameterEntry::Value`] instead.
+fn validate_fragment(bit_width: BitWidth, raw: &[u8]) -> Result<(), SerializeError> {
+    if raw.is_empty() {
+        return Err(SerializeError::InvalidFragment(format!(
+            "ciphertext fragment for bit width {} is empty",
+            u8::from(bit_width)
+        )));
+    }
+    let entry: ParameterType = rmp_serde::from_slice(raw).map_err(|e| {
+        SerializeError::InvalidFragment(format!(
+            "ciphertext fragment for bit width {} does not decode as a parameter entry: {e}",
+            u8::from(bit_width)
+        ))
+    })?;
+    let actual_bit_width = match &entry {
+        ParameterType::Ciphertext { content } => content.bit_width,
+        ParameterType::CiphertextArray { contents } => {
+            contents.first().map(|c| c.bit_width).unwrap_or(bit_width)
+        }
+        _ => bit_width,
+    };
+    if actual_bit_width != bit_width {
+        return Err(SerializeError::InvalidFragment(format!(
+            "ciphertext fragment claims bit width {} but its encoded content is bit width {}",
+            u8::from(bit_width),
+            u8::from(actual_bit_width)
+        )));
+    }
+    Ok(())
+}
+
+/// Stream-serialize parameters with magic bytes, version, and checksum header
+/// directly into `w`. Note that computing the header's checksum requires
+/// buffering the encoded payload once internally (see [`serialize_with_header_to`]);
+/// only the final write to `w` avoids a second copy.
+pub fn serialize_parameters_to<W: Write>(
+    w: &mut W,
+    params: &[ParameterType],
+) -> Result<(), SerializeError> {
+    serialize_with_header_to(w, &PARAMETERS_MAGIC, PARAMETERS_VERSION, params)
+}
+
+/// Stream-serialize outputs with magic bytes, version, and checksum header
+/// directly into `w`. See [`serialize_parameters_to`] for the buffering caveat.
+pub fn serialize_outputs_to<W: Write>(
+    w: &mut W,
+    outputs: &[L1GlweCiphertextWithBitWidth],
+) -> Result<(), SerializeError> {
+    serialize_with_header_to(w, &OUTPUT_MAGIC, OUTPUT_VERSION, outputs)
+}
+
+// Computing the header's checksum requires the full payload bytes up front,
+// so this buffers the msgpack encoding once via `serialize_with_header`
+// rather than streaming it straight into `w`; only the final write to `w` is
+// unbuffered. A leading checksum and a single-pass streaming write are
+// fundamentally at odds without a `Seek`-able `w` to patch the header after
+// the fact.
+fn serialize_with_header_to<W: Write, T: Serialize + ?Sized>(
+    w: &mut W,
+    magic: &[u8; 4],
+    version: u32,
+    payload: &T,
+) -> Result<(), SerializeError> {
+    let buf = serialize_with_header(magic, version, payload)?;
+    w.write_all(&buf).map_err(SerializeError::Io)
+}
+
 /// Deserialize parameters payload, assuming header was already validated.
 ///
 /// The caller must have validated the header via `peek_parameters_version` and
 /// pass the returned version. This function validates the version matches the
-/// expected `PARAMETERS_VERSION` and deserializes the msgpack payload.
+/// expected `PARAMETERS_VERSION` and deserializes the msgpack payload, capped
+/// at [`DeserializeLimits::DEFAULT`]. Use [`deserialize_parameters_payload_with_limits`]
+/// to pick a different policy.
 pub fn deserialize_parameters_payload(
     bytes: &[u8],
     version: u32,
 ) -> Result<Vec<ParameterType>, DeserializeError> {
-    if version != PARAMETERS_VERSION {
+    deserialize_parameters_payload_with_limits(bytes, version, DeserializeLimits::DEFAULT)
+}
+
+/// Like [`deserialize_parameters_payload`], but enforcing a caller-supplied size policy.
+///
+/// Dispatches on `version` through [`PARAMETERS_MIGRATORS`] rather than
+/// rejecting anything but [`PARAMETERS_VERSION`] outright, so parameter blobs
+/// written by older builds keep loading. `UnsupportedVersion` is reserved for
+/// versions with no registered migrator.
+pub fn deserialize_parameters_payload_with_limits(
+    bytes: &[u8],
+    version: u32,
+    limits: DeserializeLimits,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    dispatch_parameters_decoder(bytes, version, limits, true, Compatibility::Backward)
+}
+
+fn dispatch_parameters_decoder(
+    bytes: &[u8],
+    version: u32,
+    limits: DeserializeLimits,
+    reject_trailing: bool,
+    compatibility: Compatibility,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    if compatibility == Compatibility::Strict && version != PARAMETERS_VERSION {
         return Err(DeserializeError::UnsupportedVersion {
             got: version,
             expected: PARAMETERS_VERSION,
         });
     }
-    rmp_serde::from_slice(&bytes[HEADER_SIZE..]).map_err(DeserializeError::Payload)
+    let decode = PARAMETERS_MIGRATORS
+        .iter()
+        .find(|(v, _)| *v == version)
+        .map(|(_, decode)| *decode)
+        .ok_or(DeserializeError::UnsupportedVersion {
+            got: version,
+            expected: PARAMETERS_VERSION,
+        })?;
+    decode(bytes, limits, reject_trailing)
+}
+
+/// A per-version parameters payload decoder. Takes the full framed bytes
+/// (header included, in that version's own layout) and returns the decoded
+/// parameters at the *current* [`ParameterType`] schema. When a version bump
+/// only changes the wire framing (e.g. adding the checksum in version 2)
+/// rather than the parameter schema itself, the decoder just parses the old
+/// framing into the same `ParameterType`; a genuine schema revision would
+/// instead decode into a `ParameterTypeV{n}` here and map it forward.
+type ParametersDecoder =
+    fn(&[u8], DeserializeLimits, bool) -> Result<Vec<ParameterType>, DeserializeError>;
+
+/// Registered parameters decoders, oldest first. See [`ParametersDecoder`].
+const PARAMETERS_MIGRATORS: &[(u32, ParametersDecoder)] = &[
+    (1, decode_parameters_v1),
+    (2, decode_parameters_v2),
+    (3, decode_parameters_v3),
+];
+
+/// Version 1 framing: `[MAGIC][VERSION]` header, no checksum, pre-signedness schema.
+fn decode_parameters_v1(
+    bytes: &[u8],
+    limits: DeserializeLimits,
+    strict: bool,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    if bytes.len() < HEADER_SIZE {
+        return Err(DeserializeError::Peek(PeekError::TooShort));
+    }
+    let payload = &bytes[HEADER_SIZE..];
+    check_limits(payload, limits)?;
+    let legacy: Vec<ParameterTypeV2> = decode_msgpack(payload, strict)?;
+    Ok(legacy.into_iter().map(Into::into).collect())
 }
 
-/// Deserialize parameters, validating magic bytes and version.
+/// Version 2 framing: `[MAGIC][VERSION][CHECKSUM]` header, pre-signedness schema.
+fn decode_parameters_v2(
+    bytes: &[u8],
+    limits: DeserializeLimits,
+    strict: bool,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    let payload = verify_checksummed_payload(bytes)?;
+    check_limits(payload, limits)?;
+    let legacy: Vec<ParameterTypeV2> = decode_msgpack(payload, strict)?;
+    Ok(legacy.into_iter().map(Into::into).collect())
+}
+
+/// Version 3 framing (current): `[MAGIC][VERSION][CHECKSUM]` header, with
+/// explicit `Signedness` on the parameter schema itself.
+fn decode_parameters_v3(
+    bytes: &[u8],
+    limits: DeserializeLimits,
+    strict: bool,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    let payload = verify_checksummed_payload(bytes)?;
+    check_limits(payload, limits)?;
+    decode_msgpack(payload, strict)
+}
+
+/// Re-decode a parameters blob at whatever version it was written at, and
+/// re-serialize it at [`PARAMETERS_VERSION`]. Intended for bulk-upgrading
+/// artifacts stored by older builds; pairs with [`migrate_outputs`].
+pub fn migrate_parameters(bytes: &[u8]) -> Result<Vec<u8>, MigrateError> {
+    let parameters = deserialize_parameters_with_limits(bytes, DeserializeLimits::DEFAULT)?;
+    Ok(serialize_parameters(&parameters)?)
+}
+
+/// Validate that `bytes` is long enough to hold the checksummed header and
+/// that the trailing payload's CRC-32C matches the checksum recorded in it,
+/// returning the payload slice on success.
+fn verify_checksummed_payload(bytes: &[u8]) -> Result<&[u8], DeserializeError> {
+    if bytes.len() < CHECKSUMMED_HEADER_SIZE {
+        return Err(DeserializeError::Peek(PeekError::TooShort));
+    }
+    let expected = u32::from_be_bytes(
+        bytes[HEADER_SIZE..CHECKSUMMED_HEADER_SIZE]
+            .try_into()
+            .expect("slice is exactly 4 bytes"),
+    );
+    let payload = &bytes[CHECKSUMMED_HEADER_SIZE..];
+    let got = crc32c::crc32c(payload);
+    if got != expected {
+        return Err(DeserializeError::ChecksumMismatch { expected, got });
+    }
+    Ok(payload)
+}
+
+/// Deserialize parameters, validating magic bytes and version, capped at
+/// [`DeserializeLimits::DEFAULT`]. Transparently accepts either the raw binary
+/// wire format or an [`armor`]-ed text envelope (see [`deserialize_parameters_armored`]).
+///
+/// Rejects any payload with unconsumed trailing bytes after the decoded
+/// msgpack value (`DeserializeError::TrailingBytes`). Use
+/// [`deserialize_parameters_allow_trailing`] for inputs that intentionally
+/// embed the blob inside a larger stream.
 pub fn deserialize_parameters(bytes: &[u8]) -> Result<Vec<ParameterType>, DeserializeError> {
+    deserialize_parameters_with_limits(bytes, DeserializeLimits::DEFAULT)
+}
+
+/// Like [`deserialize_parameters`], but enforcing a caller-supplied size policy.
+pub fn deserialize_parameters_with_limits(
+    bytes: &[u8],
+    limits: DeserializeLimits,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    if armor::is_armored(bytes) {
+        let text = armored_str(bytes)?;
+        return deserialize_parameters_armored_with_limits_and_compatibility(
+            text,
+            limits,
+            Compatibility::Backward,
+        );
+    }
+    let version = peek_parameters_version(bytes)?;
+    dispatch_parameters_decoder(bytes, version, limits, true, Compatibility::Backward)
+}
+
+/// Like [`deserialize_parameters`], but allows unconsumed trailing bytes after
+/// the decoded msgpack value instead of rejecting them. Intended for callers
+/// that deliberately embed a parameters blob inside a larger stream or file.
+pub fn deserialize_parameters_allow_trailing(
+    bytes: &[u8],
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    deserialize_parameters_allow_trailing_with_limits(bytes, DeserializeLimits::DEFAULT)
+}
+
+/// Like [`deserialize_parameters_allow_trailing`], but enforcing a caller-supplied size policy.
+pub fn deserialize_parameters_allow_trailing_with_limits(
+    bytes: &[u8],
+    limits: DeserializeLimits,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    if armor::is_armored(bytes) {
+        let text = armored_str(bytes)?;
+        return deserialize_parameters_armored_with_limits_and_compatibility(
+            text,
+            limits,
+            Compatibility::Backward,
+        );
+    }
+    let version = peek_parameters_version(bytes)?;
+    dispatch_parameters_decoder(bytes, version, limits, false, Compatibility::Backward)
+}
+
+/// Like [`deserialize_parameters`], but with an explicit [`Compatibility`]
+/// policy instead of the implicit `Backward` default. Pass
+/// `Compatibility::Strict` to reject anything but [`PARAMETERS_VERSION`],
+/// even a version with a registered migrator.
+pub fn deserialize_parameters_with_compatibility(
+    bytes: &[u8],
+    compatibility: Compatibility,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    deserialize_parameters_with_limits_and_compatibility(
+        bytes,
+        DeserializeLimits::DEFAULT,
+        compatibility,
+    )
+}
+
+/// Like [`deserialize_parameters_with_compatibility`], but enforcing a caller-supplied size policy.
+pub fn deserialize_parameters_with_limits_and_compatibility(
+    bytes: &[u8],
+    limits: DeserializeLimits,
+    compatibility: Compatibility,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    if armor::is_armored(bytes) {
+        let text = armored_str(bytes)?;
+        return deserialize_parameters_armored_with_limits_and_compatibility(
+            text,
+            limits,
+            compatibility,
+        );
+    }
     let version = peek_parameters_version(bytes)?;
-    deserialize_parameters_payload(bytes, version)
+    dispatch_parameters_decoder(bytes, version, limits, true, compatibility)
 }
 
-/// Deserialize outputs, validating magic bytes and version.
+/// Deserialize outputs, validating magic bytes and version, capped at
+/// [`DeserializeLimits::DEFAULT`]. Transparently accepts either the raw binary
+/// wire format or an [`armor`]-ed text envelope (see [`deserialize_outputs_armored`]).
 pub fn deserialize_outputs(
     bytes: &[u8],
 ) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    deserialize_outputs_with_limits(bytes, DeserializeLimits::DEFAULT)
+}
+
+/// Like [`deserialize_outputs`], but enforcing a caller-supplied size policy.
+///
+/// Dispatches on the peeked version through [`OUTPUT_MIGRATORS`] rather than
+/// rejecting anything but [`OUTPUT_VERSION`] outright; see
+/// [`deserialize_parameters_payload_with_limits`] for the rationale.
+pub fn deserialize_outputs_with_limits(
+    bytes: &[u8],
+    limits: DeserializeLimits,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    if armor::is_armored(bytes) {
+        let text = armored_str(bytes)?;
+        return deserialize_outputs_armored_with_limits_and_compatibility(
+            text,
+            limits,
+            Compatibility::Backward,
+        );
+    }
     let version = peek_output_version(bytes)?;
-    if version != OUTPUT_VERSION {
+    dispatch_outputs_decoder(bytes, version, limits, true, Compatibility::Backward)
+}
+
+/// Like [`deserialize_outputs`], but allows unconsumed trailing bytes after
+/// the decoded msgpack value instead of rejecting them. Intended for callers
+/// that deliberately embed an outputs blob inside a larger stream or file.
+pub fn deserialize_outputs_allow_trailing(
+    bytes: &[u8],
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    deserialize_outputs_allow_trailing_with_limits(bytes, DeserializeLimits::DEFAULT)
+}
+
+/// Like [`deserialize_outputs_allow_trailing`], but enforcing a caller-supplied size policy.
+pub fn deserialize_outputs_allow_trailing_with_limits(
+    bytes: &[u8],
+    limits: DeserializeLimits,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    if armor::is_armored(bytes) {
+        let text = armored_str(bytes)?;
+        return deserialize_outputs_armored_with_limits_and_compatibility(
+            text,
+            limits,
+            Compatibility::Backward,
+        );
+    }
+    let version = peek_output_version(bytes)?;
+    dispatch_outputs_decoder(bytes, version, limits, false, Compatibility::Backward)
+}
+
+/// Like [`deserialize_outputs`], but with an explicit [`Compatibility`] policy
+/// instead of the implicit `Backward` default. Pass `Compatibility::Strict` to
+/// reject anything but [`OUTPUT_VERSION`], even a version with a registered
+/// migrator.
+pub fn deserialize_outputs_with_compatibility(
+    bytes: &[u8],
+    compatibility: Compatibility,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    deserialize_outputs_with_limits_and_compatibility(
+        bytes,
+        DeserializeLimits::DEFAULT,
+        compatibility,
+    )
+}
+
+/// Like [`deserialize_outputs_with_compatibility`], but enforcing a caller-supplied size policy.
+pub fn deserialize_outputs_with_limits_and_compatibility(
+    bytes: &[u8],
+    limits: DeserializeLimits,
+    compatibility: Compatibility,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    if armor::is_armored(bytes) {
+        let text = armored_str(bytes)?;
+        return deserialize_outputs_armored_with_limits_and_compatibility(
+            text,
+            limits,
+            compatibility,
+        );
+    }
+    let version = peek_output_version(bytes)?;
+    dispatch_outputs_decoder(bytes, version, limits, true, compatibility)
+}
+
+fn dispatch_outputs_decoder(
+    bytes: &[u8],
+    version: u32,
+    limits: DeserializeLimits,
+    reject_trailing: bool,
+    compatibility: Compatibility,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    if compatibility == Compatibility::Strict && version != OUTPUT_VERSION {
+        return Err(DeserializeError::UnsupportedVersion {
+            got: version,
+            expected: OUTPUT_VERSION,
+        });
+    }
+    let decode = OUTPUT_MIGRATORS
+        .iter()
+        .find(|(v, _)| *v == version)
+        .map(|(_, decode)| *decode)
+        .ok_or(DeserializeError::UnsupportedVersion {
+            got: version,
+            expected: OUTPUT_VERSION,
+        })?;
+    decode(bytes, limits, reject_trailing)
+}
+
+/// A per-version outputs payload decoder. See [`ParametersDecoder`] for the rationale.
+type OutputsDecoder = fn(
+    &[u8],
+    DeserializeLimits,
+    bool,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError>;
+
+/// Registered outputs decoders, oldest first. See [`OutputsDecoder`].
+const OUTPUT_MIGRATORS: &[(u32, OutputsDecoder)] = &[
+    (1, decode_outputs_v1),
+    (2, decode_outputs_v2),
+    (3, decode_outputs_v3),
+];
+
+/// Version 1 framing: `[MAGIC][VERSION]` header, no checksum, pre-signedness schema.
+fn decode_outputs_v1(
+    bytes: &[u8],
+    limits: DeserializeLimits,
+    strict: bool,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    if bytes.len() < HEADER_SIZE {
+        return Err(DeserializeError::Peek(PeekError::TooShort));
+    }
+    let payload = &bytes[HEADER_SIZE..];
+    check_limits(payload, limits)?;
+    let legacy: Vec<L1GlweCiphertextWithBitWidthV2> = decode_msgpack(payload, strict)?;
+    Ok(legacy.into_iter().map(Into::into).collect())
+}
+
+/// Version 2 framing: `[MAGIC][VERSION][CHECKSUM]` header, pre-signedness schema.
+fn decode_outputs_v2(
+    bytes: &[u8],
+    limits: DeserializeLimits,
+    strict: bool,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    let payload = verify_checksummed_payload(bytes)?;
+    check_limits(payload, limits)?;
+    let legacy: Vec<L1GlweCiphertextWithBitWidthV2> = decode_msgpack(payload, strict)?;
+    Ok(legacy.into_iter().map(Into::into).collect())
+}
+
+/// Version 3 framing (current): `[MAGIC][VERSION][CHECKSUM]` header, with
+/// explicit `Signedness` on the output schema itself.
+fn decode_outputs_v3(
+    bytes: &[u8],
+    limits: DeserializeLimits,
+    strict: bool,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    let payload = verify_checksummed_payload(bytes)?;
+    check_limits(payload, limits)?;
+    decode_msgpack(payload, strict)
+}
+
+/// Decode a msgpack-encoded value from `payload`. When `strict` is true
+/// (the default for `deserialize_parameters`/`deserialize_outputs`), this
+/// verifies the payload is fully consumed by the decode and returns
+/// `DeserializeError::TrailingBytes` otherwise; `rmp_serde::from_slice` alone
+/// silently ignores anything left after the first valid msgpack value. When
+/// `strict` is false, trailing bytes are allowed.
+pub(crate) fn decode_msgpack<T: serde::de::DeserializeOwned>(
+    payload: &[u8],
+    strict: bool,
+) -> Result<T, DeserializeError> {
+    if !strict {
+        return rmp_serde::from_slice(payload).map_err(DeserializeError::Payload);
+    }
+    let mut cursor = std::io::Cursor::new(payload);
+    let value = rmp_serde::from_read(&mut cursor).map_err(DeserializeError::Payload)?;
+    let consumed = cursor.position() as usize;
+    if consumed != payload.len() {
+        return Err(DeserializeError::TrailingBytes {
+            remaining: payload.len() - consumed,
+        });
+    }
+    Ok(value)
+}
+
+/// Re-decode an outputs blob at whatever version it was written at, and
+/// re-serialize it at [`OUTPUT_VERSION`]. See [`migrate_parameters`].
+pub fn migrate_outputs(bytes: &[u8]) -> Result<Vec<u8>, MigrateError> {
+    let outputs = deserialize_outputs_with_limits(bytes, DeserializeLimits::DEFAULT)?;
+    Ok(serialize_outputs(&outputs)?)
+}
+
+/// Rebuild the framed bytes [`PARAMETERS_MIGRATORS`]/[`OUTPUT_MIGRATORS`]
+/// expect from an already-dearmored `(version, payload)` pair, so the
+/// armored decode path can dispatch through the exact same per-version
+/// migrators as the raw binary path instead of duplicating their schema
+/// logic. Version 1 never had a checksum field; every version from 2 onward
+/// does, so the checksum is recomputed fresh over `payload` here (it isn't
+/// additional untrusted input - it's exactly the bytes about to be re-decoded).
+fn reframe_for_migrator(magic: &[u8; 4], version: u32, payload: &[u8]) -> Vec<u8> {
+    let mut framed = Vec::with_capacity(CHECKSUMMED_HEADER_SIZE + payload.len());
+    framed.extend_from_slice(magic);
+    framed.extend_from_slice(&version.to_be_bytes());
+    if version >= 2 {
+        framed.extend_from_slice(&crc32c::crc32c(payload).to_be_bytes());
+    }
+    framed.extend_from_slice(payload);
+    framed
+}
+
+fn armored_str(bytes: &[u8]) -> Result<&str, DeserializeError> {
+    std::str::from_utf8(bytes)
+        .map_err(|_| DeserializeError::Armor(ArmorError::MalformedHeader(
+            "envelope is not valid UTF-8".to_string(),
+        )))
+}
+
+/// Serialize parameters as an ASCII-armored text envelope (see the [`armor`] module).
+pub fn serialize_parameters_armored(params: &[ParameterType]) -> Result<String, SerializeError> {
+    let payload = rmp_serde::to_vec(params).map_err(SerializeError::Payload)?;
+    Ok(armor::armor(&PARAMETERS_MAGIC, PARAMETERS_VERSION, &payload))
+}
+
+/// Serialize outputs as an ASCII-armored text envelope (see the [`armor`] module).
+pub fn serialize_outputs_armored(
+    outputs: &[L1GlweCiphertextWithBitWidth],
+) -> Result<String, SerializeError> {
+    let payload = rmp_serde::to_vec(outputs).map_err(SerializeError::Payload)?;
+    Ok(armor::armor(&OUTPUT_MAGIC, OUTPUT_VERSION, &payload))
+}
+
+/// Deserialize parameters from an ASCII-armored text envelope, capped at
+/// [`DeserializeLimits::DEFAULT`].
+pub fn deserialize_parameters_armored(text: &str) -> Result<Vec<ParameterType>, DeserializeError> {
+    deserialize_parameters_armored_with_limits(text, DeserializeLimits::DEFAULT)
+}
+
+/// Like [`deserialize_parameters_armored`], but enforcing a caller-supplied size policy.
+pub fn deserialize_parameters_armored_with_limits(
+    text: &str,
+    limits: DeserializeLimits,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    deserialize_parameters_armored_with_limits_and_compatibility(
+        text,
+        limits,
+        Compatibility::Backward,
+    )
+}
+
+/// Like [`deserialize_parameters_armored`], but with an explicit
+/// [`Compatibility`] policy instead of the implicit `Backward` default.
+pub fn deserialize_parameters_armored_with_compatibility(
+    text: &str,
+    compatibility: Compatibility,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    deserialize_parameters_armored_with_limits_and_compatibility(
+        text,
+        DeserializeLimits::DEFAULT,
+        compatibility,
+    )
+}
+
+/// Like [`deserialize_parameters_armored_with_limits`], but with an explicit
+/// [`Compatibility`] policy: an older declared version is migrated through
+/// [`PARAMETERS_MIGRATORS`] (via [`reframe_for_migrator`]) the same as the
+/// raw binary path, instead of always hard-failing on anything but
+/// [`PARAMETERS_VERSION`].
+pub fn deserialize_parameters_armored_with_limits_and_compatibility(
+    text: &str,
+    limits: DeserializeLimits,
+    compatibility: Compatibility,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    let (version, payload) = armor::dearmor(text, &PARAMETERS_MAGIC)?;
+    let framed = reframe_for_migrator(&PARAMETERS_MAGIC, version, &payload);
+    dispatch_parameters_decoder(&framed, version, limits, true, compatibility)
+}
+
+/// Deserialize outputs from an ASCII-armored text envelope, capped at
+/// [`DeserializeLimits::DEFAULT`].
+pub fn deserialize_outputs_armored(
+    text: &str,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    deserialize_outputs_armored_with_limits(text, DeserializeLimits::DEFAULT)
+}
+
+/// Like [`deserialize_outputs_armored`], but enforcing a caller-supplied size policy.
+pub fn deserialize_outputs_armored_with_limits(
+    text: &str,
+    limits: DeserializeLimits,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    deserialize_outputs_armored_with_limits_and_compatibility(text, limits, Compatibility::Backward)
+}
+
+/// Like [`deserialize_outputs_armored`], but with an explicit [`Compatibility`]
+/// policy instead of the implicit `Backward` default.
+pub fn deserialize_outputs_armored_with_compatibility(
+    text: &str,
+    compatibility: Compatibility,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    deserialize_outputs_armored_with_limits_and_compatibility(
+        text,
+        DeserializeLimits::DEFAULT,
+        compatibility,
+    )
+}
+
+/// Like [`deserialize_outputs_armored_with_limits`], but with an explicit
+/// [`Compatibility`] policy; see [`deserialize_parameters_armored_with_limits_and_compatibility`].
+pub fn deserialize_outputs_armored_with_limits_and_compatibility(
+    text: &str,
+    limits: DeserializeLimits,
+    compatibility: Compatibility,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    let (version, payload) = armor::dearmor(text, &OUTPUT_MAGIC)?;
+    let framed = reframe_for_migrator(&OUTPUT_MAGIC, version, &payload);
+    dispatch_outputs_decoder(&framed, version, limits, true, compatibility)
+}
+
+/// Stream-deserialize parameters directly from `r`, peeking the header from
+/// the first [`HEADER_SIZE`] bytes before decoding the msgpack payload, so the
+/// reader's backing buffer (a file or stdin) never needs to be fully slurped
+/// into memory first. `limits.max_bytes` is enforced by capping how much of
+/// `r` the msgpack decoder may consume; `limits.max_elements` is not checked
+/// here, since that requires peeking the array-length prefix ahead of the
+/// decoder, which only the slice-based [`deserialize_parameters_with_limits`] can do.
+/// Likewise, trailing-bytes rejection (see [`deserialize_parameters`]) is not
+/// enforced here: checking it would require reading past the decoded value
+/// to see whether `r` has more to give, which isn't safe to do generically
+/// for a caller who may want to keep reading `r` afterwards.
+pub fn deserialize_parameters_from<R: Read>(r: &mut R) -> Result<Vec<ParameterType>, DeserializeError> {
+    deserialize_parameters_from_with_limits(r, DeserializeLimits::DEFAULT)
+}
+
+/// Like [`deserialize_parameters_from`], but enforcing a caller-supplied size policy.
+pub fn deserialize_parameters_from_with_limits<R: Read>(
+    r: &mut R,
+    limits: DeserializeLimits,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    deserialize_parameters_from_with_limits_and_compatibility(r, limits, Compatibility::Backward)
+}
+
+/// Like [`deserialize_parameters_from`], but with an explicit [`Compatibility`]
+/// policy instead of the implicit `Backward` default.
+pub fn deserialize_parameters_from_with_compatibility<R: Read>(
+    r: &mut R,
+    compatibility: Compatibility,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    deserialize_parameters_from_with_limits_and_compatibility(
+        r,
+        DeserializeLimits::DEFAULT,
+        compatibility,
+    )
+}
+
+/// Like [`deserialize_parameters_from_with_limits`], but with an explicit
+/// [`Compatibility`] policy. The zero-copy streaming decode in
+/// [`read_checksummed_payload`] only applies to the common case of a payload
+/// already at [`PARAMETERS_VERSION`]; an older version is migrated by
+/// buffering the rest of `r` (still bounded by `limits.max_bytes`) and
+/// dispatching through [`PARAMETERS_MIGRATORS`], the same as the
+/// slice-based path.
+pub fn deserialize_parameters_from_with_limits_and_compatibility<R: Read>(
+    r: &mut R,
+    limits: DeserializeLimits,
+    compatibility: Compatibility,
+) -> Result<Vec<ParameterType>, DeserializeError> {
+    let mut header = [0u8; HEADER_SIZE];
+    r.read_exact(&mut header).map_err(|e| match e.kind() {
+        std::io::ErrorKind::UnexpectedEof => DeserializeError::Peek(PeekError::TooShort),
+        _ => DeserializeError::Io(e),
+    })?;
+    let version = peek_version(&header, &PARAMETERS_MAGIC)?;
+    if version == PARAMETERS_VERSION {
+        return read_checksummed_payload(r, limits);
+    }
+    if compatibility == Compatibility::Strict {
+        return Err(DeserializeError::UnsupportedVersion {
+            got: version,
+            expected: PARAMETERS_VERSION,
+        });
+    }
+    let framed = read_remaining_framed(r, &header, limits)?;
+    dispatch_parameters_decoder(&framed, version, limits, true, compatibility)
+}
+
+/// Stream-deserialize outputs directly from `r`. See [`deserialize_parameters_from`]
+/// for the streaming/limits tradeoffs.
+pub fn deserialize_outputs_from<R: Read>(
+    r: &mut R,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    deserialize_outputs_from_with_limits(r, DeserializeLimits::DEFAULT)
+}
+
+/// Like [`deserialize_outputs_from`], but enforcing a caller-supplied size policy.
+pub fn deserialize_outputs_from_with_limits<R: Read>(
+    r: &mut R,
+    limits: DeserializeLimits,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    deserialize_outputs_from_with_limits_and_compatibility(r, limits, Compatibility::Backward)
+}
+
+/// Like [`deserialize_outputs_from`], but with an explicit [`Compatibility`]
+/// policy instead of the implicit `Backward` default.
+pub fn deserialize_outputs_from_with_compatibility<R: Read>(
+    r: &mut R,
+    compatibility: Compatibility,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    deserialize_outputs_from_with_limits_and_compatibility(
+        r,
+        DeserializeLimits::DEFAULT,
+        compatibility,
+    )
+}
+
+/// Like [`deserialize_outputs_from_with_limits`], but with an explicit
+/// [`Compatibility`] policy; see [`deserialize_parameters_from_with_limits_and_compatibility`].
+pub fn deserialize_outputs_from_with_limits_and_compatibility<R: Read>(
+    r: &mut R,
+    limits: DeserializeLimits,
+    compatibility: Compatibility,
+) -> Result<Vec<L1GlweCiphertextWithBitWidth>, DeserializeError> {
+    let mut header = [0u8; HEADER_SIZE];
+    r.read_exact(&mut header).map_err(|e| match e.kind() {
+        std::io::ErrorKind::UnexpectedEof => DeserializeError::Peek(PeekError::TooShort),
+        _ => DeserializeError::Io(e),
+    })?;
+    let version = peek_version(&header, &OUTPUT_MAGIC)?;
+    if version == OUTPUT_VERSION {
+        return read_checksummed_payload(r, limits);
+    }
+    if compatibility == Compatibility::Strict {
         return Err(DeserializeError::UnsupportedVersion {
             got: version,
             expected: OUTPUT_VERSION,
         });
     }
-    rmp_serde::from_slice(&bytes[HEADER_SIZE..]).map_err(DeserializeError::Payload)
+    let framed = read_remaining_framed(r, &header, limits)?;
+    dispatch_outputs_decoder(&framed, version, limits, true, compatibility)
+}
+
+/// Read whatever remains of `r` (bounded by `limits.max_bytes`, as a last
+/// line of defense against a malicious stream claiming an old version and
+/// never ending) and prepend the already-consumed `header` bytes, producing
+/// the full framed buffer [`PARAMETERS_MIGRATORS`]/[`OUTPUT_MIGRATORS`] expect.
+fn read_remaining_framed<R: Read>(
+    r: &mut R,
+    header: &[u8; HEADER_SIZE],
+    limits: DeserializeLimits,
+) -> Result<Vec<u8>, DeserializeError> {
+    let mut rest = Vec::new();
+    r.take(limits.max_bytes.unwrap_or(u64::MAX))
+        .read_to_end(&mut rest)
+        .map_err(DeserializeError::Io)?;
+    let mut framed = Vec::with_capacity(header.len() + rest.len());
+    framed.extend_from_slice(header);
+    framed.extend_from_slice(&rest);
+    Ok(framed)
+}
+
+/// Read the checksum field, then decode the msgpack payload from `r` while
+/// hashing it in a single pass, verifying the running CRC-32C against the
+/// checksum once decoding completes.
+fn read_checksummed_payload<R: Read, T: serde::de::DeserializeOwned>(
+    r: &mut R,
+    limits: DeserializeLimits,
+) -> Result<T, DeserializeError> {
+    let mut checksum_bytes = [0u8; CHECKSUM_SIZE];
+    r.read_exact(&mut checksum_bytes)
+        .map_err(DeserializeError::Io)?;
+    let expected = u32::from_be_bytes(checksum_bytes);
+
+    let limited = r.take(limits.max_bytes.unwrap_or(u64::MAX));
+    let mut hashing = ChecksummingReader { inner: limited, crc: 0 };
+    let value = rmp_serde::from_read(&mut hashing).map_err(DeserializeError::Payload)?;
+    if hashing.crc != expected {
+        return Err(DeserializeError::ChecksumMismatch {
+            expected,
+            got: hashing.crc,
+        });
+    }
+    Ok(value)
 }
+
+/// A [`Read`] wrapper that accumulates a running CRC-32C over every byte it forwards.
+struct ChecksummingReader<R> {
+    inner: R,
+    crc: u32,
+}
+
+impl<R: Read> Read for ChecksummingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.crc = crc32c::crc32c_append(self.crc, &buf[..n]);
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Signedness;
+
+    fn sample_params() -> Vec<ParameterType> {
+        vec![ParameterType::Plaintext {
+            bit_width: BitWidth::U32,
+            value: 42,
+            signedness: Signedness::Unsigned,
+        }]
+    }
+
+    #[test]
+    fn deserialize_parameters_with_limits_accepts_payload_within_bounds() {
+        let bytes = serialize_parameters(&sample_params()).unwrap();
+        let limits = DeserializeLimits {
+            max_bytes: Some(bytes.len() as u64),
+            max_elements: Some(1),
+            max_element_bytes: None,
+        };
+        let params = deserialize_parameters_with_limits(&bytes, limits).unwrap();
+        assert_eq!(params.len(), 1);
+    }
+
+    #[test]
+    fn deserialize_parameters_with_limits_rejects_oversized_payload() {
+        let bytes = serialize_parameters(&sample_params()).unwrap();
+        let payload_len = (bytes.len() - CHECKSUMMED_HEADER_SIZE) as u64;
+        let limits = DeserializeLimits {
+            max_bytes: Some(payload_len - 1),
+            max_elements: None,
+            max_element_bytes: None,
+        };
+        let err = deserialize_parameters_with_limits(&bytes, limits).unwrap_err();
+        assert!(matches!(
+            err,
+            DeserializeError::LimitExceeded {
+                limit,
+                requested
+            } if limit == payload_len - 1 && requested == payload_len
+        ));
+    }
+
+    #[test]
+    fn deserialize_parameters_round_trips_through_checksummed_header() {
+        let bytes = serialize_parameters(&sample_params()).unwrap();
+        let params = deserialize_parameters(&bytes).unwrap();
+        assert_eq!(params.len(), 1);
+    }
+
+    #[test]
+    fn deserialize_parameters_rejects_flipped_payload_byte() {
+        let mut bytes = serialize_parameters(&sample_params()).unwrap();
+        // Flip one bit in the payload without touching the recorded checksum,
+        // simulating corruption or truncation in transit.
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0x01;
+        let err = deserialize_parameters(&bytes).unwrap_err();
+        assert!(matches!(err, DeserializeError::ChecksumMismatch { .. }));
+    }
+
+    #[test]
+    fn deserialize_parameters_with_limits_rejects_oversized_element() {
+        let params = vec![ParameterType::PlaintextArray {
+            bit_width: BitWidth::U32,
+            values: vec![1, 2, 3, 4, 5],
+            signedness: Signedness::Unsigned,
+        }];
+        let bytes = serialize_parameters(&params).unwrap();
+        let limits = DeserializeLimits {
+            max_bytes: None,
+            max_elements: None,
+            // The single `PlaintextArray` entry's encoded size comfortably
+            // exceeds a handful of bytes, so this should be rejected without
+            // ever materializing the `values` vector.
+            max_element_bytes: Some(4),
+        };
+        let err = deserialize_parameters_with_limits(&bytes, limits).unwrap_err();
+        assert!(matches!(
+            err,
+            DeserializeError::LimitExceeded { limit: 4, .. }
+        ));
+    }
+
+    #[test]
+    fn deserialize_parameters_with_limits_rejects_too_many_elements() {
+        let params = vec![
+            ParameterType::Plaintext {
+                bit_width: BitWidth::U32,
+                value: 1,
+                signedness: Signedness::Unsigned,
+            },
+            ParameterType::Plaintext {
+                bit_width: BitWidth::U32,
+                value: 2,
+                signedness: Signedness::Unsigned,
+            },
+        ];
+        let bytes = serialize_parameters(&params).unwrap();
+        let limits = DeserializeLimits {
+            max_bytes: None,
+            max_elements: Some(1),
+            max_element_bytes: None,
+        };
+        let err = deserialize_parameters_with_limits(&bytes, limits).unwrap_err();
+        assert!(matches!(
+            err,
+            DeserializeError::LimitExceeded { limit: 1, requested: 2 }
+        ));
+    }
+
+    #[test]
+    fn validate_fragment_rejects_empty_raw() {
+        let err = validate_fragment(BitWidth::U32, &[]).unwrap_err();
+        assert!(matches!(err, SerializeError::InvalidFragment(msg) if msg.contains("is empty")));
+    }
+
+    #[test]
+    fn validate_fragment_rejects_bytes_that_are_not_a_parameter_entry() {
+        let raw = vec![0xc1]; // msgpack reserved/never-used byte: never a valid entry
+        let err = validate_fragment(BitWidth::U32, &raw).unwrap_err();
+        assert!(matches!(
+            err,
+            SerializeError::InvalidFragment(msg) if msg.contains("does not decode")
+        ));
+    }
+
+    #[test]
+    fn validate_fragment_accepts_well_formed_entry_regardless_of_claimed_width() {
+        // `Plaintext` carries no ciphertext bit width of its own to cross-check
+        // against, so `validate_fragment` only confirms it decodes cleanly.
+        let raw = rmp_serde::to_vec(&ParameterType::Plaintext {
+            bit_width: BitWidth::U16,
+            value: 7,
+            signedness: Signedness::Unsigned,
+        })
+        .unwrap();
+        validate_fragment(BitWidth::U32, &raw).unwrap();
+    }
+}
+
@@ -1,7 +1,9 @@
 use std::{fs::write, num::NonZeroU32, path::PathBuf};
 
 use parasol_runtime::{ComputeKey, DEFAULT_128, Encryption, SecretKey, fluent::PackedUInt16};
-use program_runner::{BitWidth, L1GlweCiphertextWithBitWidth, ParameterType, serialize_parameters};
+use program_runner::{
+    BitWidth, L1GlweCiphertextWithBitWidth, ParameterType, Signedness, serialize_parameters,
+};
 use rand::{RngCore, rng};
 use tempfile::TempDir;
 
@@ -39,11 +41,14 @@ pub fn setup() -> TestSetup {
             content: L1GlweCiphertextWithBitWidth {
                 bit_width: BitWidth::U16,
                 ciphertext: PackedUInt16::encrypt_secret(value as u128, &enc, &secret_key).inner(),
+                signedness: Signedness::Unsigned,
+                frac_bits: 0,
             },
         },
         ParameterType::OutputCiphertextArray {
             bit_width: BitWidth::U16,
             size: NonZeroU32::new(1).unwrap(),
+            signedness: Signedness::Unsigned,
         },
     ];
 
@@ -13,6 +13,7 @@ fn test_elf_file_not_present() {
         .join("no.such.program");
 
     let output = Command::new(env!("CARGO_BIN_EXE_program_runner"))
+        .arg("run")
         .arg("--elf")
         .arg(&not_present_elf)
         .arg("--func")
@@ -41,6 +42,7 @@ fn test_elf_file_not_valid() {
     let not_valid_elf = "tests/data/illegal.program";
 
     let output = Command::new(env!("CARGO_BIN_EXE_program_runner"))
+        .arg("run")
         .arg("--elf")
         .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join(not_valid_elf))
         .arg("--func")
@@ -67,6 +69,7 @@ fn test_elf_file_not_including_program() {
     let not_included_function = "nonexistent_function";
 
     let output = Command::new(env!("CARGO_BIN_EXE_program_runner"))
+        .arg("run")
         .arg("--elf")
         .arg(&test_programs)
         .arg("--func")
@@ -96,6 +99,7 @@ fn test_key_file_not_present() {
     let not_present_key = setup.compute_key_path.parent().unwrap().join("no.such.key");
 
     let output = Command::new(env!("CARGO_BIN_EXE_program_runner"))
+        .arg("run")
         .arg("--elf")
         .arg(setup::test_programs_elf())
         .arg("--func")
@@ -124,6 +128,7 @@ fn test_key_file_not_valid() {
     write(&setup.compute_key_path, "NOT_A_VALID_KEY_FILE").unwrap();
 
     let output = Command::new(env!("CARGO_BIN_EXE_program_runner"))
+        .arg("run")
         .arg("--elf")
         .arg(setup::test_programs_elf())
         .arg("--func")
@@ -156,6 +161,7 @@ fn test_params_file_not_present() {
         .join("no.such.params");
 
     let output = Command::new(env!("CARGO_BIN_EXE_program_runner"))
+        .arg("run")
         .arg("--elf")
         .arg(setup::test_programs_elf())
         .arg("--func")
@@ -184,6 +190,7 @@ fn test_params_file_not_valid() {
     write(&setup.params_path, "NOT_A_VALID_PARAMETERS_FILE").unwrap();
 
     let output = Command::new(env!("CARGO_BIN_EXE_program_runner"))
+        .arg("run")
         .arg("--elf")
         .arg(setup::test_programs_elf())
         .arg("--func")
@@ -221,6 +228,7 @@ fn test_params_version_mismatch() {
     write(&setup.params_path, &bad_params).unwrap();
 
     let output = Command::new(env!("CARGO_BIN_EXE_program_runner"))
+        .arg("run")
         .arg("--elf")
         .arg(setup::test_programs_elf())
         .arg("--func")
@@ -256,6 +264,7 @@ fn test_params_invalid_magic() {
     write(&setup.params_path, &bad_params).unwrap();
 
     let output = Command::new(env!("CARGO_BIN_EXE_program_runner"))
+        .arg("run")
         .arg("--elf")
         .arg(setup::test_programs_elf())
         .arg("--func")
@@ -286,6 +295,7 @@ fn test_params_truncated_header() {
     write(&setup.params_path, b"SPF").unwrap();
 
     let output = Command::new(env!("CARGO_BIN_EXE_program_runner"))
+        .arg("run")
         .arg("--elf")
         .arg(setup::test_programs_elf())
         .arg("--func")
@@ -10,6 +10,7 @@ fn test_inc() {
     let result_path = setup.test_dir.path().join("result.bin");
 
     let output = Command::new(env!("CARGO_BIN_EXE_program_runner"))
+        .arg("run")
         .arg("--elf")
         .arg(setup::test_programs_elf())
         .arg("--func")
@@ -53,6 +54,7 @@ fn test_inc_stdout() {
     let setup = setup::setup();
 
     let output = Command::new(env!("CARGO_BIN_EXE_program_runner"))
+        .arg("run")
         .arg("--elf")
         .arg(setup::test_programs_elf())
         .arg("--func")
@@ -13,9 +13,11 @@ mod validation;
 pub use ciphertext::PyCiphertext;
 pub use keys::{PyComputeKey, PyKeySet, PyPublicKey, PySecretKey};
 pub use parameters::{
-    deserialize_output, deserialize_parameters, get_output_version, get_parameters_version,
-    py_peek_output_version, py_peek_parameters_version, serialize_parameters, PyWireCiphertext,
-    PyWireCiphertextArray, PyWireOutputCiphertextArray, PyWirePlaintext, PyWirePlaintextArray,
+    deserialize_output, deserialize_parameters, deserialize_parameters_authenticated,
+    get_output_version, get_parameters_version, py_peek_output_version,
+    py_peek_parameters_version, serialize_parameters, serialize_parameters_authenticated,
+    PyCompatibility, PyDeserializeLimits, PyWireCiphertext, PyWireCiphertextArray,
+    PyWireOutputCiphertextArray, PyWirePlaintext, PyWirePlaintextArray,
 };
 
 /// Python module for sunscreen_fhe native bindings.
@@ -36,10 +38,14 @@ fn _native(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyWireOutputCiphertextArray>()?;
     m.add_class::<PyWirePlaintext>()?;
     m.add_class::<PyWirePlaintextArray>()?;
+    m.add_class::<PyCompatibility>()?;
+    m.add_class::<PyDeserializeLimits>()?;
 
     // Parameter serialization functions
     m.add_function(wrap_pyfunction!(serialize_parameters, m)?)?;
     m.add_function(wrap_pyfunction!(deserialize_parameters, m)?)?;
+    m.add_function(wrap_pyfunction!(serialize_parameters_authenticated, m)?)?;
+    m.add_function(wrap_pyfunction!(deserialize_parameters_authenticated, m)?)?;
 
     // Output deserialization function
     m.add_function(wrap_pyfunction!(deserialize_output, m)?)?;
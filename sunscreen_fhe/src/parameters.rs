@@ -3,9 +3,13 @@
 use std::num::NonZeroU32;
 
 use program_runner::{
-    deserialize_outputs, deserialize_parameters as deserialize_parameters_rust,
-    peek_output_version, peek_parameters_version, serialize_parameters as serialize_params_rust,
-    BitWidth, L1GlweCiphertextWithBitWidth, ParameterType, OUTPUT_VERSION, PARAMETERS_VERSION,
+    deserialize_outputs_with_limits_and_compatibility,
+    deserialize_parameters_authenticated as deserialize_parameters_authenticated_rust,
+    deserialize_parameters_with_limits_and_compatibility, peek_output_version,
+    peek_parameters_version, serialize_parameter_entries,
+    serialize_parameters_authenticated as serialize_parameters_authenticated_rust, BitWidth,
+    Compatibility, DeserializeLimits, L1GlweCiphertextWithBitWidth, ParameterEntry, ParameterType,
+    Signedness, OUTPUT_VERSION, PARAMETERS_VERSION,
 };
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyList};
@@ -23,6 +27,11 @@ use crate::validation::{from_msgpack, to_msgpack, value_error, BitWidthExt};
 pub struct PyWireCiphertext {
     data: Vec<u8>,
     bit_width: u16,
+    signed: bool,
+    /// Complete pre-encoded `ParameterType::Ciphertext` entry, cached so
+    /// [`serialize_parameters`] can splice it in as a [`ParameterEntry::CiphertextFragment`]
+    /// instead of decoding `data` and re-encoding it on every call.
+    fragment: Vec<u8>,
 }
 
 #[pymethods]
@@ -30,9 +39,14 @@ impl PyWireCiphertext {
     #[new]
     fn new(data: Vec<u8>) -> PyResult<Self> {
         let ct: L1GlweCiphertextWithBitWidth = from_msgpack(&data)?;
+        let bit_width = ct.bit_width.into();
+        let signed = ct.signedness.is_signed();
+        let fragment = to_msgpack(&ParameterType::Ciphertext { content: ct })?;
         Ok(Self {
             data,
-            bit_width: ct.bit_width.into(),
+            bit_width,
+            signed,
+            fragment,
         })
     }
 
@@ -41,6 +55,11 @@ impl PyWireCiphertext {
         self.bit_width
     }
 
+    #[getter]
+    fn signed(&self) -> bool {
+        self.signed
+    }
+
     #[getter]
     fn data(&self) -> &[u8] {
         &self.data
@@ -53,22 +72,31 @@ impl PyWireCiphertext {
 pub struct PyWireCiphertextArray {
     data: Vec<Vec<u8>>,
     bit_width: u16,
+    signed: bool,
+    /// Complete pre-encoded `ParameterType::CiphertextArray` entry, cached so
+    /// [`serialize_parameters`] can splice it in as a
+    /// [`ParameterEntry::CiphertextArrayFragment`] instead of decoding every
+    /// element of `data` and re-encoding them on every call.
+    fragment: Vec<u8>,
 }
 
 #[pymethods]
 impl PyWireCiphertextArray {
     #[new]
     fn new(data: Vec<Vec<u8>>) -> PyResult<Self> {
-        let first_ct: L1GlweCiphertextWithBitWidth = data
+        let mut contents: Vec<L1GlweCiphertextWithBitWidth> = Vec::with_capacity(data.len());
+        for bytes in &data {
+            contents.push(from_msgpack(bytes)?);
+        }
+        let bit_width: u16 = contents
             .first()
-            .map(|first| from_msgpack(first))
-            .transpose()?
-            .ok_or_else(|| value_error("ciphertext array cannot be empty"))?;
-        let bit_width: u16 = first_ct.bit_width.into();
+            .ok_or_else(|| value_error("ciphertext array cannot be empty"))?
+            .bit_width
+            .into();
+        let signed = contents[0].signedness.is_signed();
 
         // Validate all elements have the same bit width
-        for (i, bytes) in data.iter().enumerate().skip(1) {
-            let ct: L1GlweCiphertextWithBitWidth = from_msgpack(bytes)?;
+        for (i, ct) in contents.iter().enumerate().skip(1) {
             let ct_bit_width: u16 = ct.bit_width.into();
             if ct_bit_width != bit_width {
                 return Err(value_error(format!(
@@ -77,7 +105,14 @@ impl PyWireCiphertextArray {
             }
         }
 
-        Ok(Self { data, bit_width })
+        let fragment = to_msgpack(&ParameterType::CiphertextArray { contents })?;
+
+        Ok(Self {
+            data,
+            bit_width,
+            signed,
+            fragment,
+        })
     }
 
     #[getter]
@@ -85,6 +120,11 @@ impl PyWireCiphertextArray {
         self.bit_width
     }
 
+    #[getter]
+    fn signed(&self) -> bool {
+        self.signed
+    }
+
     #[getter]
     fn data(&self) -> Vec<Vec<u8>> {
         self.data.clone()
@@ -101,13 +141,19 @@ impl PyWireCiphertextArray {
 pub struct PyWireOutputCiphertextArray {
     bit_width: u16,
     size: u32,
+    signed: bool,
 }
 
 #[pymethods]
 impl PyWireOutputCiphertextArray {
     #[new]
-    fn new(bit_width: u16, size: u32) -> Self {
-        Self { bit_width, size }
+    #[pyo3(signature = (bit_width, size, signed=false))]
+    fn new(bit_width: u16, size: u32, signed: bool) -> Self {
+        Self {
+            bit_width,
+            size,
+            signed,
+        }
     }
 
     #[getter]
@@ -119,6 +165,11 @@ impl PyWireOutputCiphertextArray {
     fn size(&self) -> u32 {
         self.size
     }
+
+    #[getter]
+    fn signed(&self) -> bool {
+        self.signed
+    }
 }
 
 /// Plaintext value for wire format (internal).
@@ -127,13 +178,19 @@ impl PyWireOutputCiphertextArray {
 pub struct PyWirePlaintext {
     value: u64,
     bit_width: u16,
+    signed: bool,
 }
 
 #[pymethods]
 impl PyWirePlaintext {
     #[new]
-    fn new(value: u64, bit_width: u16) -> Self {
-        Self { value, bit_width }
+    #[pyo3(signature = (value, bit_width, signed=false))]
+    fn new(value: u64, bit_width: u16, signed: bool) -> Self {
+        Self {
+            value,
+            bit_width,
+            signed,
+        }
     }
 
     #[getter]
@@ -145,6 +202,11 @@ impl PyWirePlaintext {
     fn value(&self) -> u64 {
         self.value
     }
+
+    #[getter]
+    fn signed(&self) -> bool {
+        self.signed
+    }
 }
 
 /// Plaintext array for wire format (internal).
@@ -153,13 +215,19 @@ impl PyWirePlaintext {
 pub struct PyWirePlaintextArray {
     values: Vec<u64>,
     bit_width: u16,
+    signed: bool,
 }
 
 #[pymethods]
 impl PyWirePlaintextArray {
     #[new]
-    fn new(values: Vec<u64>, bit_width: u16) -> Self {
-        Self { values, bit_width }
+    #[pyo3(signature = (values, bit_width, signed=false))]
+    fn new(values: Vec<u64>, bit_width: u16, signed: bool) -> Self {
+        Self {
+            values,
+            bit_width,
+            signed,
+        }
     }
 
     #[getter]
@@ -172,21 +240,106 @@ impl PyWirePlaintextArray {
         self.values.clone()
     }
 
+    #[getter]
+    fn signed(&self) -> bool {
+        self.signed
+    }
+
     fn __len__(&self) -> usize {
         self.values.len()
     }
 }
 
+/// Version-matching policy for [`deserialize_parameters`]/[`deserialize_output`].
+///
+/// `Backward` (the default) accepts any version with a registered migrator
+/// and migrates it forward; `Strict` rejects anything but the current
+/// version. Mirrors `program_runner::Compatibility`.
+#[pyclass(name = "Compatibility", eq, eq_int)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PyCompatibility {
+    Strict,
+    Backward,
+}
+
+impl From<PyCompatibility> for Compatibility {
+    fn from(value: PyCompatibility) -> Self {
+        match value {
+            PyCompatibility::Strict => Compatibility::Strict,
+            PyCompatibility::Backward => Compatibility::Backward,
+        }
+    }
+}
+
+/// Size-limit policy for [`deserialize_parameters`]/[`deserialize_output`],
+/// guarding against a hostile payload driving an unbounded allocation before
+/// any of it has been validated. `None` for any field means unbounded.
+/// Mirrors `program_runner::DeserializeLimits`; defaults to
+/// `program_runner::DeserializeLimits::DEFAULT` when not constructed explicitly.
+#[pyclass(name = "DeserializeLimits", eq)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct PyDeserializeLimits {
+    max_bytes: Option<u64>,
+    max_elements: Option<u64>,
+    max_element_bytes: Option<u64>,
+}
+
+#[pymethods]
+impl PyDeserializeLimits {
+    #[new]
+    #[pyo3(signature = (max_bytes=None, max_elements=None, max_element_bytes=None))]
+    fn new(
+        max_bytes: Option<u64>,
+        max_elements: Option<u64>,
+        max_element_bytes: Option<u64>,
+    ) -> Self {
+        Self {
+            max_bytes,
+            max_elements,
+            max_element_bytes,
+        }
+    }
+
+    #[getter]
+    fn max_bytes(&self) -> Option<u64> {
+        self.max_bytes
+    }
+
+    #[getter]
+    fn max_elements(&self) -> Option<u64> {
+        self.max_elements
+    }
+
+    #[getter]
+    fn max_element_bytes(&self) -> Option<u64> {
+        self.max_element_bytes
+    }
+}
+
+impl From<PyDeserializeLimits> for DeserializeLimits {
+    fn from(value: PyDeserializeLimits) -> Self {
+        Self {
+            max_bytes: value.max_bytes,
+            max_elements: value.max_elements.map(|n| n as usize),
+            max_element_bytes: value.max_element_bytes,
+        }
+    }
+}
+
+fn signedness(signed: bool) -> Signedness {
+    if signed {
+        Signedness::Signed
+    } else {
+        Signedness::Unsigned
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Parameter serialization functions
 // -----------------------------------------------------------------------------
 
-/// Serialize parameter entries from Python to MessagePack Vec<ParameterType>.
-///
-/// Accepts a list of Wire* objects representing parameter entries.
-/// Returns serialized MessagePack bytes.
-#[pyfunction]
-pub fn serialize_parameters(py: Python<'_>, entries: &Bound<'_, PyList>) -> PyResult<Py<PyBytes>> {
+/// Convert a Python list of Wire* objects into `Vec<ParameterType>`.
+fn entries_to_parameters(entries: &Bound<'_, PyList>) -> PyResult<Vec<ParameterType>> {
     let mut params = Vec::with_capacity(entries.len());
 
     for entry in entries.iter() {
@@ -204,82 +357,159 @@ pub fn serialize_parameters(py: Python<'_>, entries: &Bound<'_, PyList>) -> PyRe
             let bit_width = BitWidth::try_from_u16(out.bit_width)?;
             let size = NonZeroU32::new(out.size)
                 .ok_or_else(|| value_error("output size must be at least 1"))?;
-            params.push(ParameterType::OutputCiphertextArray { bit_width, size });
+            params.push(ParameterType::OutputCiphertextArray {
+                bit_width,
+                size,
+                signedness: signedness(out.signed),
+            });
         } else if let Ok(pt) = entry.extract::<PyRef<PyWirePlaintext>>() {
             let bit_width = BitWidth::try_from_u16(pt.bit_width)?;
             params.push(ParameterType::Plaintext {
                 bit_width,
                 value: pt.value,
+                signedness: signedness(pt.signed),
             });
         } else if let Ok(arr) = entry.extract::<PyRef<PyWirePlaintextArray>>() {
             let bit_width = BitWidth::try_from_u16(arr.bit_width)?;
             params.push(ParameterType::PlaintextArray {
                 bit_width,
                 values: arr.values.clone(),
+                signedness: signedness(arr.signed),
             });
         } else {
             return Err(value_error("unknown parameter type"));
         }
     }
 
-    let bytes = serialize_params_rust(&params).map_err(|e| value_error(e.to_string()))?;
-    Ok(PyBytes::new(py, &bytes).into())
+    Ok(params)
 }
 
-/// Deserialize MessagePack bytes to list of Wire* parameter objects.
-///
-/// Returns a list of WireCiphertext, WireCiphertextArray, WireOutput,
-/// WirePlaintext, or WirePlaintextArray objects.
-#[pyfunction]
-pub fn deserialize_parameters(py: Python<'_>, bytes: &[u8]) -> PyResult<Py<PyList>> {
-    let params = deserialize_parameters_rust(bytes).map_err(|e| value_error(e.to_string()))?;
+/// Like [`entries_to_parameters`], but produces [`ParameterEntry`] values for
+/// [`serialize_parameters`]: `WireCiphertext`/`WireCiphertextArray` entries
+/// reuse their cached fragment instead of being decoded into a
+/// [`ParameterType`] only for `serialize_parameter_entries` to re-encode it,
+/// which is exactly the redundant round trip fragments exist to skip.
+fn entries_to_parameter_entries(entries: &Bound<'_, PyList>) -> PyResult<Vec<ParameterEntry>> {
+    let mut params = Vec::with_capacity(entries.len());
+
+    for entry in entries.iter() {
+        if let Ok(ct) = entry.extract::<PyRef<PyWireCiphertext>>() {
+            let bit_width = BitWidth::try_from_u16(ct.bit_width)?;
+            params.push(ParameterEntry::CiphertextFragment {
+                bit_width,
+                raw: ct.fragment.clone(),
+            });
+        } else if let Ok(arr) = entry.extract::<PyRef<PyWireCiphertextArray>>() {
+            let bit_width = BitWidth::try_from_u16(arr.bit_width)?;
+            params.push(ParameterEntry::CiphertextArrayFragment {
+                bit_width,
+                raw: arr.fragment.clone(),
+            });
+        } else if let Ok(out) = entry.extract::<PyRef<PyWireOutputCiphertextArray>>() {
+            let bit_width = BitWidth::try_from_u16(out.bit_width)?;
+            let size = NonZeroU32::new(out.size)
+                .ok_or_else(|| value_error("output size must be at least 1"))?;
+            params.push(ParameterEntry::Value(ParameterType::OutputCiphertextArray {
+                bit_width,
+                size,
+                signedness: signedness(out.signed),
+            }));
+        } else if let Ok(pt) = entry.extract::<PyRef<PyWirePlaintext>>() {
+            let bit_width = BitWidth::try_from_u16(pt.bit_width)?;
+            params.push(ParameterEntry::Value(ParameterType::Plaintext {
+                bit_width,
+                value: pt.value,
+                signedness: signedness(pt.signed),
+            }));
+        } else if let Ok(arr) = entry.extract::<PyRef<PyWirePlaintextArray>>() {
+            let bit_width = BitWidth::try_from_u16(arr.bit_width)?;
+            params.push(ParameterEntry::Value(ParameterType::PlaintextArray {
+                bit_width,
+                values: arr.values.clone(),
+                signedness: signedness(arr.signed),
+            }));
+        } else {
+            return Err(value_error("unknown parameter type"));
+        }
+    }
+
+    Ok(params)
+}
+
+/// Convert `Vec<ParameterType>` into a Python list of Wire* objects.
+fn parameters_to_entries(py: Python<'_>, params: Vec<ParameterType>) -> PyResult<Py<PyList>> {
     let result = PyList::empty(py);
 
     for param in params {
         match param {
             ParameterType::Ciphertext { content } => {
                 let bit_width: u16 = content.bit_width.into();
+                let signed = content.signedness.is_signed();
                 let ct_bytes = to_msgpack(&content)?;
                 result.append(
                     PyWireCiphertext {
                         data: ct_bytes,
                         bit_width,
+                        signed,
                     }
                     .into_pyobject(py)?,
                 )?;
             }
             ParameterType::CiphertextArray { contents } => {
-                let bit_width: u16 = contents
+                let first = contents
                     .first()
-                    .map(|c| c.bit_width.into())
                     .ok_or_else(|| value_error("ciphertext array cannot be empty"))?;
+                let bit_width: u16 = first.bit_width.into();
+                let signed = first.signedness.is_signed();
                 let data: Vec<Vec<u8>> =
                     contents.iter().map(to_msgpack).collect::<PyResult<_>>()?;
-                result.append(PyWireCiphertextArray { data, bit_width }.into_pyobject(py)?)?;
+                result.append(
+                    PyWireCiphertextArray {
+                        data,
+                        bit_width,
+                        signed,
+                    }
+                    .into_pyobject(py)?,
+                )?;
             }
-            ParameterType::OutputCiphertextArray { bit_width, size } => {
+            ParameterType::OutputCiphertextArray {
+                bit_width,
+                size,
+                signedness,
+            } => {
                 result.append(
                     PyWireOutputCiphertextArray {
                         bit_width: bit_width.into(),
                         size: size.get(),
+                        signed: signedness.is_signed(),
                     }
                     .into_pyobject(py)?,
                 )?;
             }
-            ParameterType::Plaintext { bit_width, value } => {
+            ParameterType::Plaintext {
+                bit_width,
+                value,
+                signedness,
+            } => {
                 result.append(
                     PyWirePlaintext {
                         value,
                         bit_width: bit_width.into(),
+                        signed: signedness.is_signed(),
                     }
                     .into_pyobject(py)?,
                 )?;
             }
-            ParameterType::PlaintextArray { bit_width, values } => {
+            ParameterType::PlaintextArray {
+                bit_width,
+                values,
+                signedness,
+            } => {
                 result.append(
                     PyWirePlaintextArray {
                         values,
                         bit_width: bit_width.into(),
+                        signed: signedness.is_signed(),
                     }
                     .into_pyobject(py)?,
                 )?;
@@ -290,12 +520,55 @@ pub fn deserialize_parameters(py: Python<'_>, bytes: &[u8]) -> PyResult<Py<PyLis
     Ok(result.into())
 }
 
+/// Serialize parameter entries from Python to MessagePack Vec<ParameterType>.
+///
+/// Accepts a list of Wire* objects representing parameter entries.
+/// `WireCiphertext`/`WireCiphertextArray` entries are spliced in from their
+/// cached pre-encoded fragment rather than decoded and re-encoded.
+/// Returns serialized MessagePack bytes.
+#[pyfunction]
+pub fn serialize_parameters(py: Python<'_>, entries: &Bound<'_, PyList>) -> PyResult<Py<PyBytes>> {
+    let entries = entries_to_parameter_entries(entries)?;
+    let bytes = serialize_parameter_entries(&entries).map_err(|e| value_error(e.to_string()))?;
+    Ok(PyBytes::new(py, &bytes).into())
+}
+
+/// Deserialize MessagePack bytes to list of Wire* parameter objects.
+///
+/// Returns a list of WireCiphertext, WireCiphertextArray, WireOutput,
+/// WirePlaintext, or WirePlaintextArray objects.
+///
+/// Args:
+///     bytes: Parameter payload bytes
+///     compatibility: Version-matching policy. Defaults to `Compatibility.Backward`,
+///         which accepts and migrates any version with a registered decoder.
+///     limits: Size-limit policy guarding against hostile input. Defaults to
+///         `program_runner`'s built-in `DeserializeLimits.DEFAULT`.
+#[pyfunction]
+#[pyo3(signature = (bytes, compatibility=PyCompatibility::Backward, limits=None))]
+pub fn deserialize_parameters(
+    py: Python<'_>,
+    bytes: &[u8],
+    compatibility: PyCompatibility,
+    limits: Option<PyDeserializeLimits>,
+) -> PyResult<Py<PyList>> {
+    let limits = limits.map_or(DeserializeLimits::DEFAULT, Into::into);
+    let params =
+        deserialize_parameters_with_limits_and_compatibility(bytes, limits, compatibility.into())
+            .map_err(|e| value_error(e.to_string()))?;
+    parameters_to_entries(py, params)
+}
+
 /// Deserialize versioned output bytes to a list of Ciphertext objects.
 ///
 /// Accepts MessagePack bytes and returns a list of PyCiphertext objects.
 ///
 /// Args:
 ///     bytes: MessagePack-serialized output with magic bytes and version header
+///     compatibility: Version-matching policy. Defaults to `Compatibility.Backward`,
+///         which accepts and migrates any version with a registered decoder.
+///     limits: Size-limit policy guarding against hostile input. Defaults to
+///         `program_runner`'s built-in `DeserializeLimits.DEFAULT`.
 ///
 /// Returns:
 ///     List of Ciphertext objects
@@ -303,8 +576,17 @@ pub fn deserialize_parameters(py: Python<'_>, bytes: &[u8]) -> PyResult<Py<PyLis
 /// Raises:
 ///     ValueError: If version is not supported or deserialization fails
 #[pyfunction]
-pub fn deserialize_output(py: Python<'_>, bytes: &[u8]) -> PyResult<Py<PyList>> {
-    let outputs = deserialize_outputs(bytes).map_err(|e| value_error(e.to_string()))?;
+#[pyo3(signature = (bytes, compatibility=PyCompatibility::Backward, limits=None))]
+pub fn deserialize_output(
+    py: Python<'_>,
+    bytes: &[u8],
+    compatibility: PyCompatibility,
+    limits: Option<PyDeserializeLimits>,
+) -> PyResult<Py<PyList>> {
+    let limits = limits.map_or(DeserializeLimits::DEFAULT, Into::into);
+    let outputs =
+        deserialize_outputs_with_limits_and_compatibility(bytes, limits, compatibility.into())
+            .map_err(|e| value_error(e.to_string()))?;
 
     let result = PyList::empty(py);
     for ct_with_bw in outputs {
@@ -315,6 +597,56 @@ pub fn deserialize_output(py: Python<'_>, bytes: &[u8]) -> PyResult<Py<PyList>>
     Ok(result.into())
 }
 
+/// Serialize parameter entries with an HMAC-SHA256 authentication tag under `key`.
+///
+/// Unlike [`serialize_parameters`], the resulting blob carries a tag computed
+/// over its header and payload, so [`deserialize_parameters_authenticated`]
+/// can detect tampering or truncation in transit. Intended for deployments
+/// that ship ciphertext parameters across an untrusted channel.
+///
+/// Args:
+///     entries: List of Wire* objects representing parameter entries
+///     key: Shared HMAC key
+///
+/// Returns:
+///     Authenticated MessagePack bytes (distinct magic from `serialize_parameters`)
+#[pyfunction]
+pub fn serialize_parameters_authenticated(
+    py: Python<'_>,
+    entries: &Bound<'_, PyList>,
+    key: &[u8],
+) -> PyResult<Py<PyBytes>> {
+    let params = entries_to_parameters(entries)?;
+    let bytes = serialize_parameters_authenticated_rust(&params, key)
+        .map_err(|e| value_error(e.to_string()))?;
+    Ok(PyBytes::new(py, &bytes).into())
+}
+
+/// Verify and deserialize an authenticated parameters blob produced by
+/// [`serialize_parameters_authenticated`] under the same `key`.
+///
+/// Args:
+///     bytes: Authenticated MessagePack bytes
+///     key: Shared HMAC key used to produce `bytes`
+///
+/// Returns:
+///     A list of WireCiphertext, WireCiphertextArray, WireOutput,
+///     WirePlaintext, or WirePlaintextArray objects
+///
+/// Raises:
+///     ValueError: If the tag doesn't verify, the version is unsupported, or
+///         deserialization fails
+#[pyfunction]
+pub fn deserialize_parameters_authenticated(
+    py: Python<'_>,
+    bytes: &[u8],
+    key: &[u8],
+) -> PyResult<Py<PyList>> {
+    let params = deserialize_parameters_authenticated_rust(bytes, key)
+        .map_err(|e| value_error(e.to_string()))?;
+    parameters_to_entries(py, params)
+}
+
 /// Peek the version number from parameter bytes without full deserialization.
 ///
 /// This is useful for checking compatibility before attempting to deserialize.
@@ -362,3 +694,34 @@ pub fn get_parameters_version() -> u32 {
 pub fn get_output_version() -> u32 {
     OUTPUT_VERSION
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn py_deserialize_limits_converts_to_program_runner_limits() {
+        let py_limits = PyDeserializeLimits::new(Some(64), Some(8), Some(16));
+        let limits: DeserializeLimits = py_limits.into();
+        assert_eq!(limits.max_bytes, Some(64));
+        assert_eq!(limits.max_elements, Some(8));
+        assert_eq!(limits.max_element_bytes, Some(16));
+    }
+
+    #[test]
+    fn py_deserialize_parameters_rejects_payload_exceeding_python_supplied_limits() {
+        Python::attach(|py| {
+            let entries = PyList::empty(py);
+            let bytes = serialize_parameters(py, &entries).unwrap();
+            let bytes = bytes.bind(py).as_bytes();
+
+            // An empty parameter list still encodes a one-byte msgpack array
+            // marker as its payload (the header is not counted against
+            // `max_bytes`), so a `max_bytes` of 0 is guaranteed to reject it.
+            let limits = PyDeserializeLimits::new(Some(0), None, None);
+            let err = deserialize_parameters(py, bytes, PyCompatibility::Backward, Some(limits))
+                .unwrap_err();
+            assert!(err.to_string().contains("size limit exceeded"));
+        });
+    }
+}
@@ -1,15 +1,51 @@
 //! Ciphertext type and encryption/decryption operations.
 
+use aes_gcm_siv::aead::{Aead, KeyInit};
+use aes_gcm_siv::{Aes256GcmSiv, Key, Nonce};
 use parasol_runtime::fluent::{PackedUInt16, PackedUInt32, PackedUInt64, PackedUInt8};
 use parasol_runtime::L1GlweCiphertext;
-use program_runner::L1GlweCiphertextWithBitWidth;
+use program_runner::{L1GlweCiphertextWithBitWidth, Signedness};
 use pyo3::prelude::*;
-use pyo3::types::PyAnyMethods;
+use pyo3::types::{PyAnyMethods, PyList};
+use rand::{rng, RngCore};
 
 use parasol_runtime::Encryption;
 
 use crate::keys::{PyPublicKey, PySecretKey};
-use crate::validation::{from_msgpack, to_msgpack, to_signed, BitWidth, BitWidthExt};
+use crate::reencrypt::PyReEncryptionKey;
+use crate::validation::{from_msgpack, to_msgpack, to_signed, value_error, BitWidth, BitWidthExt};
+
+/// Header line marking the start of an armored ciphertext (see [`PyCiphertext::to_armored`]).
+const ARMOR_BEGIN: &str = "----- BEGIN PARASOL CIPHERTEXT -----";
+/// Footer line marking the end of an armored ciphertext.
+const ARMOR_END: &str = "----- END PARASOL CIPHERTEXT -----";
+/// Column width the Base85 body is wrapped at, matching `program_runner`'s armor format.
+const ARMOR_WRAP_COLUMNS: usize = 64;
+
+/// Number of GLWE polynomial coefficients `encrypt_batch`/`decrypt_batch` may
+/// pack lanes into. This crate only ever encrypts through the fixed-width
+/// `fluent::PackedUInt8/16/32/64` helpers, so 64 is the widest coefficient
+/// range any ciphertext this crate produces actually carries, even though the
+/// underlying GLWE polynomial degree is larger.
+const MAX_LANE_BITS: usize = 64;
+
+/// AES-256-GCM-SIV nonce size in bytes, used by
+/// `to_authenticated_bytes`/`from_authenticated_bytes`.
+const AUTH_NONCE_SIZE: usize = 12;
+
+/// Lowercase hex-encoded SHA-256 digest of `bytes`.
+fn sha256_hex(bytes: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(bytes).iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Validate and wrap a caller-supplied AES-256 key for `aes_gcm_siv`.
+fn aes_key(key: &[u8]) -> PyResult<&Key<Aes256GcmSiv>> {
+    if key.len() != 32 {
+        return Err(value_error("key must be 32 bytes for AES-256-GCM-SIV"));
+    }
+    Ok(Key::<Aes256GcmSiv>::from_slice(key))
+}
 
 /// An encrypted value (ciphertext) with associated bit width.
 ///
@@ -20,8 +56,20 @@ use crate::validation::{from_msgpack, to_msgpack, to_signed, BitWidth, BitWidthE
 pub struct PyCiphertext {
     /// The underlying ciphertext data.
     ciphertext: L1GlweCiphertext,
-    /// Validated bit width (8, 16, 32, or 64).
+    /// Validated bit width (8, 16, 32, or 64). For a ciphertext produced by
+    /// `encrypt_batch`, this is the per-lane width, not the container width.
     bit_width: BitWidth,
+    /// Signedness carried on the wire, used as `decrypt`'s default interpretation.
+    signedness: Signedness,
+    /// Number of disjoint `bit_width`-wide lanes packed into this ciphertext
+    /// by `encrypt_batch` (1 for a ciphertext produced by the scalar
+    /// `encrypt`). Not preserved across `to_bytes`/`from_bytes`: the wire
+    /// format only carries a single `bit_width`, so a batch ciphertext that
+    /// crosses the wire comes back with `lane_count` reset to 1.
+    lane_count: u32,
+    /// Fixed-point fractional bits (0 for a plain integer ciphertext). See
+    /// [`Self::encrypt`]/[`Self::decrypt`].
+    frac_bits: u8,
 }
 
 impl std::fmt::Debug for PyCiphertext {
@@ -29,6 +77,9 @@ impl std::fmt::Debug for PyCiphertext {
         f.debug_struct("PyCiphertext")
             .field("bit_width", &self.bit_width)
             .field("ciphertext", &"<L1GlweCiphertext>")
+            .field("signedness", &self.signedness)
+            .field("lane_count", &self.lane_count)
+            .field("frac_bits", &self.frac_bits)
             .finish()
     }
 }
@@ -37,16 +88,38 @@ impl PyCiphertext {
     /// Create a new PyCiphertext from raw components.
     ///
     /// This is the single constructor that ensures bit_width is always valid.
-    fn new(ciphertext: L1GlweCiphertext, bit_width: BitWidth) -> Self {
+    /// `lane_count` is always 1; use [`Self::new_batch`] for `encrypt_batch`.
+    fn new(ciphertext: L1GlweCiphertext, bit_width: BitWidth, signedness: Signedness) -> Self {
         Self {
             ciphertext,
             bit_width,
+            signedness,
+            lane_count: 1,
+            frac_bits: 0,
+        }
+    }
+
+    /// Create a new PyCiphertext packing `lane_count` disjoint `bit_width`-wide lanes.
+    fn new_batch(
+        ciphertext: L1GlweCiphertext,
+        bit_width: BitWidth,
+        signedness: Signedness,
+        lane_count: u32,
+    ) -> Self {
+        Self {
+            ciphertext,
+            bit_width,
+            signedness,
+            lane_count,
+            frac_bits: 0,
         }
     }
 
     /// Parse from the wire format.
     pub(crate) fn from_wire_format(inner: L1GlweCiphertextWithBitWidth) -> Self {
-        Self::new(inner.ciphertext, inner.bit_width)
+        let mut ct = Self::new(inner.ciphertext, inner.bit_width, inner.signedness);
+        ct.frac_bits = inner.frac_bits;
+        ct
     }
 
     /// Convert to the wire format for serialization.
@@ -54,6 +127,8 @@ impl PyCiphertext {
         L1GlweCiphertextWithBitWidth {
             bit_width: self.bit_width,
             ciphertext: self.ciphertext.clone(),
+            signedness: self.signedness,
+            frac_bits: self.frac_bits,
         }
     }
 
@@ -87,6 +162,7 @@ impl PyCiphertext {
     pub(crate) fn encrypt_with_bit_width(
         value: u64,
         bit_width: BitWidth,
+        signedness: Signedness,
         encryption: &parasol_runtime::Encryption,
         public_key: &PyPublicKey,
     ) -> Self {
@@ -105,18 +181,37 @@ impl PyCiphertext {
             }
         };
 
-        Self::new(ciphertext, bit_width)
+        Self::new(ciphertext, bit_width, signedness)
     }
 }
 
 #[pymethods]
 impl PyCiphertext {
-    /// Get the bit width of the encrypted value.
+    /// Get the bit width of the encrypted value (per-lane width for a batch ciphertext).
     #[getter]
     fn bit_width(&self) -> u32 {
         self.bit_width.into()
     }
 
+    /// Number of disjoint `bit_width`-wide lanes packed into this ciphertext
+    /// by `encrypt_batch` (1 for a ciphertext from the scalar `encrypt`).
+    #[getter]
+    fn lane_count(&self) -> u32 {
+        self.lane_count
+    }
+
+    /// Alias for [`Self::lane_count`], for callers who think in terms of slots.
+    #[getter]
+    fn slot_count(&self) -> u32 {
+        self.lane_count
+    }
+
+    /// Number of fixed-point fractional bits (0 for a plain integer ciphertext).
+    #[getter]
+    fn frac_bits(&self) -> u8 {
+        self.frac_bits
+    }
+
     /// Serialize the ciphertext to MessagePack bytes.
     fn to_bytes(&self) -> PyResult<Vec<u8>> {
         to_msgpack(&self.to_wire_format())
@@ -129,23 +224,172 @@ impl PyCiphertext {
         Ok(Self::from_wire_format(inner))
     }
 
+    /// Serialize the ciphertext with an AES-256-GCM-SIV authentication tag,
+    /// so a caller can detect silent corruption or truncation of its own
+    /// stored ciphertexts on an untrusted round trip (e.g. through a server
+    /// that shouldn't be able to tamper with results undetected). GCM-SIV is
+    /// nonce-misuse-resistant, but a fresh random nonce is still generated
+    /// and prepended on every call. The plain [`Self::to_bytes`]/[`Self::from_bytes`]
+    /// path is untouched for callers who don't need authentication.
+    ///
+    /// Args:
+    ///     key: 32-byte AES-256 key
+    ///
+    /// Raises:
+    ///     ValueError: If `key` is not 32 bytes
+    fn to_authenticated_bytes(&self, key: &[u8]) -> PyResult<Vec<u8>> {
+        let cipher = Aes256GcmSiv::new(aes_key(key)?);
+        let wire_bytes = to_msgpack(&self.to_wire_format())?;
+
+        let mut nonce_bytes = [0u8; AUTH_NONCE_SIZE];
+        rng().fill_bytes(&mut nonce_bytes);
+        let nonce = Nonce::from(nonce_bytes);
+        let sealed = cipher
+            .encrypt(&nonce, wire_bytes.as_slice())
+            .map_err(|_| value_error("authenticated encryption failed"))?;
+
+        let mut out = Vec::with_capacity(AUTH_NONCE_SIZE + sealed.len());
+        out.extend_from_slice(&nonce_bytes);
+        out.extend_from_slice(&sealed);
+        Ok(out)
+    }
+
+    /// Verify and deserialize a ciphertext produced by [`Self::to_authenticated_bytes`]
+    /// under the same `key`.
+    ///
+    /// Args:
+    ///     bytes: The nonce-prefixed, tag-appended ciphertext
+    ///     key: 32-byte AES-256 key
+    ///
+    /// Raises:
+    ///     ValueError: If `key` is not 32 bytes, `bytes` is too short to
+    ///         contain a nonce, or the authentication tag doesn't match
+    ///         (wrong key, or the data was tampered with or truncated)
+    #[staticmethod]
+    fn from_authenticated_bytes(bytes: &[u8], key: &[u8]) -> PyResult<Self> {
+        let cipher = Aes256GcmSiv::new(aes_key(key)?);
+        if bytes.len() < AUTH_NONCE_SIZE {
+            return Err(value_error(
+                "authenticated ciphertext is too short to contain a nonce",
+            ));
+        }
+        let (nonce_bytes, sealed) = bytes.split_at(AUTH_NONCE_SIZE);
+        let nonce = Nonce::from_slice(nonce_bytes);
+        let wire_bytes = cipher
+            .decrypt(nonce, sealed)
+            .map_err(|_| value_error("authentication failed: wrong key or tampered data"))?;
+
+        let inner: L1GlweCiphertextWithBitWidth = from_msgpack(&wire_bytes)?;
+        Ok(Self::from_wire_format(inner))
+    }
+
+    /// Serialize the ciphertext to an ASCII-armored, text-safe representation.
+    ///
+    /// Wraps the same wire bytes as [`Self::to_bytes`] between
+    /// `----- BEGIN PARASOL CIPHERTEXT -----`/`----- END ... -----` markers,
+    /// Base85-encoded (~25% overhead, versus ~33% for Base64), with a
+    /// SHA-256 checksum line so truncation or corruption is caught on parse.
+    /// Safe to paste into JSON, logs, or an email body.
+    fn to_armored(&self) -> PyResult<String> {
+        let wire_bytes = to_msgpack(&self.to_wire_format())?;
+        let checksum = sha256_hex(&wire_bytes);
+        let body = base85::encode(&wire_bytes);
+
+        let mut out = String::new();
+        out.push_str(ARMOR_BEGIN);
+        out.push('\n');
+        out.push_str(&format!("SHA256:{checksum}\n"));
+        for chunk in body.as_bytes().chunks(ARMOR_WRAP_COLUMNS) {
+            out.push_str(std::str::from_utf8(chunk).expect("base85 output is ASCII"));
+            out.push('\n');
+        }
+        out.push_str(ARMOR_END);
+        out.push('\n');
+        Ok(out)
+    }
+
+    /// Parse a ciphertext from the armored text format produced by [`Self::to_armored`].
+    ///
+    /// Validates the BEGIN/END markers and recomputes the SHA-256 checksum
+    /// over the decoded bytes before deserializing.
+    ///
+    /// Raises:
+    ///     ValueError: If a marker is missing, the Base85 body is malformed,
+    ///         or the checksum doesn't match (truncated or corrupted input)
+    #[staticmethod]
+    fn from_armored(text: &str) -> PyResult<Self> {
+        let mut lines = text.lines();
+
+        let header = lines
+            .next()
+            .ok_or_else(|| value_error("empty armored ciphertext"))?;
+        if header.trim() != ARMOR_BEGIN {
+            return Err(value_error(format!("missing '{ARMOR_BEGIN}' header")));
+        }
+
+        let checksum_line = lines
+            .next()
+            .ok_or_else(|| value_error("armored ciphertext is missing checksum line"))?;
+        let expected_checksum = checksum_line
+            .strip_prefix("SHA256:")
+            .ok_or_else(|| value_error(format!("malformed checksum line {checksum_line:?}")))?;
+
+        let mut body = String::new();
+        let mut found_end = false;
+        for line in lines {
+            if line.trim() == ARMOR_END {
+                found_end = true;
+                break;
+            }
+            body.push_str(line.trim());
+        }
+        if !found_end {
+            return Err(value_error(format!("missing '{ARMOR_END}' footer")));
+        }
+
+        let wire_bytes = base85::decode(&body).map_err(|e| value_error(format!("invalid base85 body: {e}")))?;
+        let got_checksum = sha256_hex(&wire_bytes);
+        if got_checksum != expected_checksum {
+            return Err(value_error(format!(
+                "checksum mismatch: expected {expected_checksum}, got {got_checksum}"
+            )));
+        }
+
+        let inner: L1GlweCiphertextWithBitWidth = from_msgpack(&wire_bytes)?;
+        Ok(Self::from_wire_format(inner))
+    }
+
     /// Decrypt this ciphertext using a secret key.
     ///
     /// Args:
     ///     secret_key: The secret key for decryption
-    ///     signed: If True, interpret result as signed (two's complement)
+    ///     signed: If True/False, interpret result as signed/unsigned
+    ///         (two's complement), overriding the ciphertext's own wire
+    ///         signedness. If not given, the value's own signedness is used,
+    ///         so round-tripping through `encrypt(..., signed=True)` no
+    ///         longer requires the caller to separately remember to pass
+    ///         `signed=True` again here. Ignored for a fixed-point ciphertext
+    ///         (`frac_bits > 0`), which is always reconstructed as signed.
     ///
     /// Returns:
-    ///     Decrypted integer value (i64 if signed, u64 if unsigned)
+    ///     Decrypted value: a float if `frac_bits > 0`, otherwise an integer
+    ///     (i64 if signed, u64 if unsigned)
+    #[pyo3(signature = (secret_key, signed=None))]
     fn decrypt(
         &self,
         py: Python<'_>,
         secret_key: &PySecretKey,
-        signed: bool,
+        signed: Option<bool>,
     ) -> PyResult<Py<PyAny>> {
         let encryption = Encryption::default();
         let unsigned = self.decrypt_impl(&encryption, secret_key)?;
-        if signed {
+        if self.frac_bits > 0 {
+            let signed_val = to_signed(unsigned, self.bit_width);
+            let scale = (1u64 << self.frac_bits) as f64;
+            let value = signed_val as f64 / scale;
+            return Ok(value.into_pyobject(py)?.into_any().unbind());
+        }
+        if signed.unwrap_or(self.signedness.is_signed()) {
             let signed_val = to_signed(unsigned, self.bit_width);
             Ok(signed_val.into_pyobject(py)?.into_any().unbind())
         } else {
@@ -153,40 +397,245 @@ impl PyCiphertext {
         }
     }
 
-    /// Encrypt an integer value with a public key.
+    /// Encrypt a value with a public key.
     ///
     /// Args:
-    ///     value: Integer value to encrypt (i64 for signed, u64 for unsigned)
+    ///     value: Value to encrypt. An int (i64 for signed, u64 for unsigned)
+    ///         if `frac_bits` is 0, otherwise a float.
     ///     public_key: Public key for encryption
     ///     bit_width: Must be 8, 16, 32, or 64
     ///     signed: If True, treat value as signed (two's complement)
+    ///     frac_bits: Number of fixed-point fractional bits. When nonzero,
+    ///         `value` is encoded as the integer `round(value * 2**frac_bits)`
+    ///         and `decrypt` later returns a float. Must be less than `bit_width`.
     ///
     /// Returns:
     ///     Encrypted ciphertext
     ///
     /// Raises:
-    ///     ValueError: If bit_width is not 8, 16, 32, or 64
+    ///     ValueError: If bit_width is not 8, 16, 32, or 64, or `frac_bits`
+    ///         is not less than `bit_width`
     ///     OverflowError: If value cannot be converted to the expected type
     #[staticmethod]
+    #[pyo3(signature = (value, public_key, bit_width, signed, frac_bits=0))]
     fn encrypt(
         value: &Bound<'_, PyAny>,
         public_key: &PyPublicKey,
         bit_width: u16,
         signed: bool,
+        frac_bits: u8,
     ) -> PyResult<Self> {
-        let bit_width = BitWidth::try_from_u16(bit_width)?;
+        let bit_width_enum = BitWidth::try_from_u16(bit_width)?;
+        if u32::from(frac_bits) >= u32::from(bit_width) {
+            return Err(value_error("frac_bits must be less than bit_width"));
+        }
         let encryption = Encryption::default();
-        let unsigned_value = if signed {
+        let signedness = if signed {
+            Signedness::Signed
+        } else {
+            Signedness::Unsigned
+        };
+        let unsigned_value = if frac_bits > 0 {
+            let x: f64 = value.extract()?;
+            let scaled = (x * (1u64 << frac_bits) as f64).round();
+            if signed {
+                bit_width_enum.signed_to_unsigned(scaled as i64)
+            } else {
+                scaled as u64
+            }
+        } else if signed {
             let v: i64 = value.extract()?;
-            bit_width.signed_to_unsigned(v)
+            bit_width_enum.signed_to_unsigned(v)
         } else {
             value.extract::<u64>()?
         };
-        Ok(Self::encrypt_with_bit_width(
+        let mut ct = Self::encrypt_with_bit_width(
             unsigned_value,
-            bit_width,
+            bit_width_enum,
+            signedness,
+            &encryption,
+            public_key,
+        );
+        ct.frac_bits = frac_bits;
+        Ok(ct)
+    }
+
+    /// Encrypt multiple values into the disjoint coefficient slots of a
+    /// single ciphertext: value `k` occupies coefficients
+    /// `[k * bit_width, (k + 1) * bit_width)`. Lets a caller amortize one
+    /// encryption/serialization over many values and run element-wise
+    /// homomorphic programs across lanes.
+    ///
+    /// Args:
+    ///     values: Lane values, one per slot (i64 for signed, u64 for unsigned)
+    ///     public_key: Public key for encryption
+    ///     bit_width: Per-lane bit width; must be 8, 16, 32, or 64
+    ///     signed: If True, treat each lane as signed (two's complement)
+    ///
+    /// Returns:
+    ///     A single ciphertext packing all lanes
+    ///
+    /// Raises:
+    ///     ValueError: If `values` is empty, a value overflows its lane
+    ///         width, or `len(values) * bit_width` exceeds the coefficient
+    ///         capacity of a single ciphertext
+    #[staticmethod]
+    fn encrypt_batch(
+        values: &Bound<'_, PyList>,
+        public_key: &PyPublicKey,
+        bit_width: u16,
+        signed: bool,
+    ) -> PyResult<Self> {
+        let lane_width = BitWidth::try_from_u16(bit_width)?;
+        let lane_count = values.len();
+        if lane_count == 0 {
+            return Err(value_error("encrypt_batch requires at least one value"));
+        }
+        let lane_bits = usize::from(bit_width);
+        let total_bits = lane_count * lane_bits;
+        if total_bits > MAX_LANE_BITS {
+            return Err(value_error(format!(
+                "{lane_count} values at {bit_width} bits each ({total_bits} total) exceed the \
+                 {MAX_LANE_BITS}-bit lane capacity of a single ciphertext"
+            )));
+        }
+        let container_bits = [8u16, 16, 32, 64]
+            .into_iter()
+            .find(|&w| usize::from(w) >= total_bits)
+            .expect("total_bits <= MAX_LANE_BITS is covered by the 64-bit case");
+        let container_width = BitWidth::try_from_u16(container_bits)?;
+
+        let signedness = if signed {
+            Signedness::Signed
+        } else {
+            Signedness::Unsigned
+        };
+        let mut packed: u64 = 0;
+        for (k, value) in values.iter().enumerate() {
+            let lane_unsigned = if signed {
+                let v: i64 = value.extract()?;
+                if v < lane_width.min_signed() || v > lane_width.max_signed() {
+                    return Err(value_error(format!(
+                        "value {v} at lane {k} overflows signed {bit_width}-bit lane width"
+                    )));
+                }
+                lane_width.signed_to_unsigned(v)
+            } else {
+                value.extract::<u64>()?
+            };
+            if lane_unsigned > lane_width.max_unsigned() {
+                return Err(value_error(format!(
+                    "value at lane {k} does not fit in {bit_width} bits"
+                )));
+            }
+            packed |= lane_unsigned << (k * lane_bits);
+        }
+
+        let encryption = Encryption::default();
+        let ct = Self::encrypt_with_bit_width(
+            packed,
+            container_width,
+            signedness,
             &encryption,
             public_key,
+        );
+        Ok(Self::new_batch(
+            ct.ciphertext,
+            lane_width,
+            signedness,
+            lane_count as u32,
         ))
     }
+
+    /// Decrypt all lanes packed by `encrypt_batch`, returning one Python
+    /// value per lane. Generalizes the scalar `decrypt`'s bit-summation loop
+    /// to walk each `bit_width`-wide slot independently.
+    ///
+    /// Args:
+    ///     secret_key: The secret key for decryption
+    ///     signed: If True/False, interpret every lane as signed/unsigned,
+    ///         overriding the ciphertext's own wire signedness; see `decrypt`.
+    ///
+    /// Returns:
+    ///     A list of `lane_count` decrypted values (i64 if signed, u64 if unsigned)
+    #[pyo3(signature = (secret_key, signed=None))]
+    fn decrypt_batch(
+        &self,
+        py: Python<'_>,
+        secret_key: &PySecretKey,
+        signed: Option<bool>,
+    ) -> PyResult<Py<PyAny>> {
+        let encryption = Encryption::default();
+        let decrypted = encryption.decrypt_glwe_l1(&self.ciphertext, &secret_key.inner);
+        let coeffs = decrypted.coeffs();
+        let lane_bits = usize::from(self.bit_width);
+        let signed = signed.unwrap_or(self.signedness.is_signed());
+
+        let result = PyList::empty(py);
+        for k in 0..self.lane_count as usize {
+            let unsigned: u64 = coeffs
+                .iter()
+                .skip(k * lane_bits)
+                .take(lane_bits)
+                .enumerate()
+                .map(|(i, &v)| v << i)
+                .sum();
+            if signed {
+                result.append(to_signed(unsigned, self.bit_width).into_pyobject(py)?)?;
+            } else {
+                result.append(unsigned.into_pyobject(py)?)?;
+            }
+        }
+        Ok(result.into_any().unbind())
+    }
+
+    /// Re-encrypt (key-switch) this ciphertext so it becomes decryptable
+    /// under a different secret key, without ever decrypting it.
+    /// `bit_width`, `signedness`, and `lane_count` all round-trip unchanged;
+    /// only the decryption key changes.
+    ///
+    /// Args:
+    ///     rk: A `ReEncryptionKey` generated from the source secret key and
+    ///         the target public key
+    ///
+    /// Returns:
+    ///     A new ciphertext decryptable under the re-encryption key's target secret key
+    pub(crate) fn reencrypt(&self, rk: &PyReEncryptionKey) -> Self {
+        let ciphertext = rk.apply(&self.ciphertext);
+        let mut ct = Self::new_batch(ciphertext, self.bit_width, self.signedness, self.lane_count);
+        ct.frac_bits = self.frac_bits;
+        ct
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_batch_rejects_signed_value_overflowing_lane_width() {
+        Python::attach(|py| {
+            let secret_key = PySecretKey::generate();
+            let public_key = PyPublicKey::from_secret_key(&secret_key);
+
+            let values = PyList::empty(py);
+            values.append(1000i64.into_pyobject(py).unwrap()).unwrap();
+
+            let err = PyCiphertext::encrypt_batch(&values, &public_key, 8, true).unwrap_err();
+            assert!(err.to_string().contains("overflows signed 8-bit lane width"));
+        });
+    }
+
+    #[test]
+    fn encrypt_batch_accepts_signed_value_within_lane_width() {
+        Python::attach(|py| {
+            let secret_key = PySecretKey::generate();
+            let public_key = PyPublicKey::from_secret_key(&secret_key);
+
+            let values = PyList::empty(py);
+            values.append((-100i64).into_pyobject(py).unwrap()).unwrap();
+
+            assert!(PyCiphertext::encrypt_batch(&values, &public_key, 8, true).is_ok());
+        });
+    }
 }
@@ -3,8 +3,9 @@
 use std::num::NonZeroU32;
 
 use program_runner::{
-    BitWidth, L1GlweCiphertextWithBitWidth, ParameterType, VersionedOutput, VersionedParameters,
-    OUTPUT_VERSION, PARAMETERS_VERSION,
+    deserialize_outputs as deserialize_outputs_rust,
+    deserialize_parameters as deserialize_parameters_rust, serialize_parameter_entries, BitWidth,
+    L1GlweCiphertextWithBitWidth, ParameterEntry, ParameterType, Signedness,
 };
 use pyo3::prelude::*;
 use pyo3::types::{PyBytes, PyList};
@@ -22,6 +23,11 @@ use crate::validation::{from_msgpack, to_msgpack, value_error, BitWidthExt};
 pub struct PyWireCiphertext {
     data: Vec<u8>,
     bit_width: u16,
+    signed: bool,
+    /// Complete pre-encoded `ParameterType::Ciphertext` entry, cached so
+    /// [`serialize_parameters`] can splice it in as a [`ParameterEntry::CiphertextFragment`]
+    /// instead of decoding `data` and re-encoding it on every call.
+    fragment: Vec<u8>,
 }
 
 #[pymethods]
@@ -29,9 +35,14 @@ impl PyWireCiphertext {
     #[new]
     fn new(data: Vec<u8>) -> PyResult<Self> {
         let ct: L1GlweCiphertextWithBitWidth = from_msgpack(&data)?;
+        let bit_width = ct.bit_width.into();
+        let signed = ct.signedness.is_signed();
+        let fragment = to_msgpack(&ParameterType::Ciphertext { content: ct })?;
         Ok(Self {
             data,
-            bit_width: ct.bit_width.into(),
+            bit_width,
+            signed,
+            fragment,
         })
     }
 
@@ -40,6 +51,11 @@ impl PyWireCiphertext {
         self.bit_width
     }
 
+    #[getter]
+    fn signed(&self) -> bool {
+        self.signed
+    }
+
     #[getter]
     fn data(&self) -> &[u8] {
         &self.data
@@ -52,19 +68,34 @@ impl PyWireCiphertext {
 pub struct PyWireCiphertextArray {
     data: Vec<Vec<u8>>,
     bit_width: u16,
+    signed: bool,
+    /// Complete pre-encoded `ParameterType::CiphertextArray` entry, cached so
+    /// [`serialize_parameters`] can splice it in as a
+    /// [`ParameterEntry::CiphertextArrayFragment`] instead of decoding every
+    /// element of `data` and re-encoding them on every call.
+    fragment: Vec<u8>,
 }
 
 #[pymethods]
 impl PyWireCiphertextArray {
     #[new]
     fn new(data: Vec<Vec<u8>>) -> PyResult<Self> {
-        let bit_width = data
+        let contents: Vec<L1GlweCiphertextWithBitWidth> = data
+            .iter()
+            .map(|bytes| from_msgpack(bytes))
+            .collect::<PyResult<_>>()?;
+        let first_ct = contents
             .first()
-            .map(|first| from_msgpack::<L1GlweCiphertextWithBitWidth>(first))
-            .transpose()?
-            .map(|ct| ct.bit_width.into())
             .ok_or_else(|| value_error("ciphertext array cannot be empty"))?;
-        Ok(Self { data, bit_width })
+        let bit_width = first_ct.bit_width.into();
+        let signed = first_ct.signedness.is_signed();
+        let fragment = to_msgpack(&ParameterType::CiphertextArray { contents })?;
+        Ok(Self {
+            data,
+            bit_width,
+            signed,
+            fragment,
+        })
     }
 
     #[getter]
@@ -72,6 +103,11 @@ impl PyWireCiphertextArray {
         self.bit_width
     }
 
+    #[getter]
+    fn signed(&self) -> bool {
+        self.signed
+    }
+
     #[getter]
     fn data(&self) -> Vec<Vec<u8>> {
         self.data.clone()
@@ -88,13 +124,19 @@ impl PyWireCiphertextArray {
 pub struct PyWireOutputCiphertextArray {
     bit_width: u16,
     size: u32,
+    signed: bool,
 }
 
 #[pymethods]
 impl PyWireOutputCiphertextArray {
     #[new]
-    fn new(bit_width: u16, size: u32) -> Self {
-        Self { bit_width, size }
+    #[pyo3(signature = (bit_width, size, signed=false))]
+    fn new(bit_width: u16, size: u32, signed: bool) -> Self {
+        Self {
+            bit_width,
+            size,
+            signed,
+        }
     }
 
     #[getter]
@@ -106,6 +148,11 @@ impl PyWireOutputCiphertextArray {
     fn size(&self) -> u32 {
         self.size
     }
+
+    #[getter]
+    fn signed(&self) -> bool {
+        self.signed
+    }
 }
 
 /// Plaintext value for wire format (internal).
@@ -114,13 +161,19 @@ impl PyWireOutputCiphertextArray {
 pub struct PyWirePlaintext {
     value: u64,
     bit_width: u16,
+    signed: bool,
 }
 
 #[pymethods]
 impl PyWirePlaintext {
     #[new]
-    fn new(value: u64, bit_width: u16) -> Self {
-        Self { value, bit_width }
+    #[pyo3(signature = (value, bit_width, signed=false))]
+    fn new(value: u64, bit_width: u16, signed: bool) -> Self {
+        Self {
+            value,
+            bit_width,
+            signed,
+        }
     }
 
     #[getter]
@@ -132,6 +185,11 @@ impl PyWirePlaintext {
     fn value(&self) -> u64 {
         self.value
     }
+
+    #[getter]
+    fn signed(&self) -> bool {
+        self.signed
+    }
 }
 
 /// Plaintext array for wire format (internal).
@@ -140,13 +198,19 @@ impl PyWirePlaintext {
 pub struct PyWirePlaintextArray {
     values: Vec<u64>,
     bit_width: u16,
+    signed: bool,
 }
 
 #[pymethods]
 impl PyWirePlaintextArray {
     #[new]
-    fn new(values: Vec<u64>, bit_width: u16) -> Self {
-        Self { values, bit_width }
+    #[pyo3(signature = (values, bit_width, signed=false))]
+    fn new(values: Vec<u64>, bit_width: u16, signed: bool) -> Self {
+        Self {
+            values,
+            bit_width,
+            signed,
+        }
     }
 
     #[getter]
@@ -159,58 +223,80 @@ impl PyWirePlaintextArray {
         self.values.clone()
     }
 
+    #[getter]
+    fn signed(&self) -> bool {
+        self.signed
+    }
+
     fn __len__(&self) -> usize {
         self.values.len()
     }
 }
 
+fn signedness(signed: bool) -> Signedness {
+    if signed {
+        Signedness::Signed
+    } else {
+        Signedness::Unsigned
+    }
+}
+
 // -----------------------------------------------------------------------------
 // Parameter serialization functions
 // -----------------------------------------------------------------------------
 
-/// Serialize parameter entries from Python to MessagePack Vec<ParameterType>.
+/// Serialize parameter entries from Python to the `program_runner` wire format.
 ///
 /// Accepts a list of Wire* objects representing parameter entries.
-/// Returns serialized MessagePack bytes.
+/// `WireCiphertext`/`WireCiphertextArray` entries are spliced in from their
+/// cached pre-encoded fragment rather than decoded and re-encoded.
+/// Returns serialized MessagePack bytes with the magic/version/checksum header.
 #[pyfunction]
 pub fn serialize_parameters(py: Python<'_>, entries: &Bound<'_, PyList>) -> PyResult<Py<PyBytes>> {
     let mut params = Vec::with_capacity(entries.len());
 
     for entry in entries.iter() {
         if let Ok(ct) = entry.extract::<PyRef<PyWireCiphertext>>() {
-            let inner: L1GlweCiphertextWithBitWidth = from_msgpack(&ct.data)?;
-            params.push(ParameterType::Ciphertext { content: inner });
+            let bit_width = BitWidth::try_from_u16(ct.bit_width)?;
+            params.push(ParameterEntry::CiphertextFragment {
+                bit_width,
+                raw: ct.fragment.clone(),
+            });
         } else if let Ok(arr) = entry.extract::<PyRef<PyWireCiphertextArray>>() {
-            let mut cts = Vec::with_capacity(arr.data.len());
-            for bytes in &arr.data {
-                let ct: L1GlweCiphertextWithBitWidth = from_msgpack(bytes)?;
-                cts.push(ct);
-            }
-            params.push(ParameterType::CiphertextArray { contents: cts });
+            let bit_width = BitWidth::try_from_u16(arr.bit_width)?;
+            params.push(ParameterEntry::CiphertextArrayFragment {
+                bit_width,
+                raw: arr.fragment.clone(),
+            });
         } else if let Ok(out) = entry.extract::<PyRef<PyWireOutputCiphertextArray>>() {
             let bit_width = BitWidth::try_from_u16(out.bit_width)?;
             let size = NonZeroU32::new(out.size)
                 .ok_or_else(|| value_error("output size must be at least 1"))?;
-            params.push(ParameterType::OutputCiphertextArray { bit_width, size });
+            params.push(ParameterEntry::Value(ParameterType::OutputCiphertextArray {
+                bit_width,
+                size,
+                signedness: signedness(out.signed),
+            }));
         } else if let Ok(pt) = entry.extract::<PyRef<PyWirePlaintext>>() {
             let bit_width = BitWidth::try_from_u16(pt.bit_width)?;
-            params.push(ParameterType::Plaintext {
+            params.push(ParameterEntry::Value(ParameterType::Plaintext {
                 bit_width,
                 value: pt.value,
-            });
+                signedness: signedness(pt.signed),
+            }));
         } else if let Ok(arr) = entry.extract::<PyRef<PyWirePlaintextArray>>() {
             let bit_width = BitWidth::try_from_u16(arr.bit_width)?;
-            params.push(ParameterType::PlaintextArray {
+            params.push(ParameterEntry::Value(ParameterType::PlaintextArray {
                 bit_width,
                 values: arr.values.clone(),
-            });
+                signedness: signedness(arr.signed),
+            }));
         } else {
             return Err(value_error("unknown parameter type"));
         }
     }
 
-    let versioned = VersionedParameters::new(params);
-    let bytes = to_msgpack(&versioned)?;
+    let bytes = serialize_parameter_entries(&params).map_err(|e| value_error(e.to_string()))?;
     Ok(PyBytes::new(py, &bytes).into())
 }
 
@@ -220,63 +306,79 @@ pub fn serialize_parameters(py: Python<'_>, entries: &Bound<'_, PyList>) -> PyRe
 /// WirePlaintext, or WirePlaintextArray objects.
 #[pyfunction]
 pub fn deserialize_parameters(py: Python<'_>, bytes: &[u8]) -> PyResult<Py<PyList>> {
-    let versioned: VersionedParameters = from_msgpack(bytes)?;
-
-    if versioned.version != PARAMETERS_VERSION {
-        return Err(value_error(format!(
-            "unsupported parameters version {}, expected {}",
-            versioned.version, PARAMETERS_VERSION
-        )));
-    }
-
-    let params = versioned.parameters;
+    let params = deserialize_parameters_rust(bytes).map_err(|e| value_error(e.to_string()))?;
     let result = PyList::empty(py);
 
     for param in params {
         match param {
             ParameterType::Ciphertext { content } => {
                 let bit_width: u16 = content.bit_width.into();
+                let signed = content.signedness.is_signed();
                 let ct_bytes = to_msgpack(&content)?;
                 result.append(
                     PyWireCiphertext {
                         data: ct_bytes,
                         bit_width,
+                        signed,
                     }
                     .into_pyobject(py)?,
                 )?;
             }
             ParameterType::CiphertextArray { contents } => {
-                let bit_width: u16 = contents
+                let first = contents
                     .first()
-                    .map(|c| c.bit_width.into())
                     .ok_or_else(|| value_error("ciphertext array cannot be empty"))?;
+                let bit_width: u16 = first.bit_width.into();
+                let signed = first.signedness.is_signed();
                 let data: Vec<Vec<u8>> =
                     contents.iter().map(to_msgpack).collect::<PyResult<_>>()?;
-                result.append(PyWireCiphertextArray { data, bit_width }.into_pyobject(py)?)?;
+                result.append(
+                    PyWireCiphertextArray {
+                        data,
+                        bit_width,
+                        signed,
+                    }
+                    .into_pyobject(py)?,
+                )?;
             }
-            ParameterType::OutputCiphertextArray { bit_width, size } => {
+            ParameterType::OutputCiphertextArray {
+                bit_width,
+                size,
+                signedness,
+            } => {
                 result.append(
                     PyWireOutputCiphertextArray {
                         bit_width: bit_width.into(),
                         size: size.get(),
+                        signed: signedness.is_signed(),
                     }
                     .into_pyobject(py)?,
                 )?;
             }
-            ParameterType::Plaintext { bit_width, value } => {
+            ParameterType::Plaintext {
+                bit_width,
+                value,
+                signedness,
+            } => {
                 result.append(
                     PyWirePlaintext {
                         value,
                         bit_width: bit_width.into(),
+                        signed: signedness.is_signed(),
                     }
                     .into_pyobject(py)?,
                 )?;
             }
-            ParameterType::PlaintextArray { bit_width, values } => {
+            ParameterType::PlaintextArray {
+                bit_width,
+                values,
+                signedness,
+            } => {
                 result.append(
                     PyWirePlaintextArray {
                         values,
                         bit_width: bit_width.into(),
+                        signed: signedness.is_signed(),
                     }
                     .into_pyobject(py)?,
                 )?;
@@ -287,13 +389,13 @@ pub fn deserialize_parameters(py: Python<'_>, bytes: &[u8]) -> PyResult<Py<PyLis
     Ok(result.into())
 }
 
-/// Deserialize versioned output bytes to a list of Ciphertext objects.
+/// Deserialize output bytes to a list of Ciphertext objects.
 ///
-/// Accepts MessagePack bytes containing a VersionedOutput struct and returns
-/// a list of PyCiphertext objects.
+/// Accepts MessagePack bytes with the `program_runner` magic/version/checksum
+/// header and returns a list of PyCiphertext objects.
 ///
 /// Args:
-///     bytes: MessagePack-serialized VersionedOutput
+///     bytes: MessagePack-serialized output
 ///
 /// Returns:
 ///     List of Ciphertext objects
@@ -302,17 +404,10 @@ pub fn deserialize_parameters(py: Python<'_>, bytes: &[u8]) -> PyResult<Py<PyLis
 ///     ValueError: If version is not supported or deserialization fails
 #[pyfunction]
 pub fn deserialize_outputs(py: Python<'_>, bytes: &[u8]) -> PyResult<Py<PyList>> {
-    let versioned: VersionedOutput = from_msgpack(bytes)?;
-
-    if versioned.version != OUTPUT_VERSION {
-        return Err(value_error(format!(
-            "unsupported output version {}, expected {}",
-            versioned.version, OUTPUT_VERSION
-        )));
-    }
+    let outputs = deserialize_outputs_rust(bytes).map_err(|e| value_error(e.to_string()))?;
 
     let result = PyList::empty(py);
-    for ct_with_bw in versioned.outputs {
+    for ct_with_bw in outputs {
         let ciphertext = PyCiphertext::from_wire_format(ct_with_bw);
         result.append(ciphertext.into_pyobject(py)?)?;
     }
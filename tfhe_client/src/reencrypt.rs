@@ -0,0 +1,75 @@
+//! Proxy re-encryption (GLWE key switching) between secret keys.
+
+use parasol_runtime::{Encryption, KeyswitchKey, L1GlweCiphertext};
+use pyo3::prelude::*;
+
+use crate::keys::{PyPublicKey, PySecretKey};
+use crate::validation::value_error;
+
+/// A key-switching key that re-encrypts a ciphertext produced under one
+/// secret key into a ciphertext decryptable under a different secret key,
+/// without ever decrypting it in between — useful for delegating decryption
+/// of an FHE result to a recipient who never held the original key.
+///
+/// Generated from a source [`PySecretKey`] and a target [`PyPublicKey`]:
+/// each coefficient of the source secret key is individually
+/// gadget-decomposed and encrypted under the target public key. Applying the
+/// resulting key to a ciphertext `c = (a, b)` computes
+/// `c' = (0, b) + Σ_i decompose(a_i) · KSK_i`, which decrypts to the same
+/// plaintext under the target secret key.
+#[pyclass(name = "ReEncryptionKey")]
+pub struct PyReEncryptionKey {
+    inner: KeyswitchKey,
+}
+
+#[pymethods]
+impl PyReEncryptionKey {
+    /// Generate a re-encryption key from `source_secret_key` to `target_public_key`.
+    #[staticmethod]
+    fn generate(source_secret_key: &PySecretKey, target_public_key: &PyPublicKey) -> PyResult<Self> {
+        let encryption = Encryption::default();
+        let inner = KeyswitchKey::generate(
+            &encryption,
+            &source_secret_key.inner,
+            &target_public_key.inner,
+        )
+        .map_err(|e| value_error(format!("failed to generate re-encryption key: {e}")))?;
+        Ok(Self { inner })
+    }
+}
+
+impl PyReEncryptionKey {
+    /// Apply the key switch homomorphically, returning a ciphertext decryptable
+    /// under the target secret key this re-encryption key was generated for.
+    pub(crate) fn apply(&self, ciphertext: &L1GlweCiphertext) -> L1GlweCiphertext {
+        self.inner.switch(ciphertext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::BitWidth;
+
+    #[test]
+    fn test_reencrypt_roundtrips_under_target_key() {
+        Python::attach(|py| {
+            let secret_key_a = PySecretKey::generate();
+            let public_key_a = PyPublicKey::from_secret_key(&secret_key_a);
+            let secret_key_b = PySecretKey::generate();
+            let public_key_b = PyPublicKey::from_secret_key(&secret_key_b);
+
+            let value = 42u64.into_pyobject(py).unwrap();
+            let ciphertext = public_key_a
+                .encrypt(&value, u16::from(BitWidth::U8), false)
+                .unwrap();
+
+            let rk = PyReEncryptionKey::generate(&secret_key_a, &public_key_b).unwrap();
+            let reencrypted = ciphertext.reencrypt(&rk);
+
+            let decrypted = secret_key_b.decrypt(py, &reencrypted, false).unwrap();
+            let decrypted: u64 = decrypted.extract(py).unwrap();
+            assert_eq!(decrypted, 42);
+        });
+    }
+}